@@ -0,0 +1,486 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::hashes::{self, AccountHash, ContractHash};
+use crate::message;
+use crate::units;
+
+const DEFAULT_CONTRACT_HASH: &str = "6a545487ba47c62bdf02f68a9d8ada590fef2a1d28778dd5b346d63927e61b4a";
+
+/// Configuration shared by all handlers, swapped as a whole on reload
+pub type SharedConfig = Arc<RwLock<FacilitatorConfig>>;
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(String),
+    Parse(String),
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(msg) => write!(f, "failed to read config file: {}", msg),
+            ConfigError::Parse(msg) => write!(f, "failed to parse config file: {}", msg),
+            ConfigError::Invalid(msg) => write!(f, "invalid config: {}", msg),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeeRates {
+    pub base_rate: u64,
+    pub instruction_rate: u64,
+    pub priority_multiplier: f64,
+}
+
+/// Smallest and largest claim amount accepted in a token, as decimal
+/// base-unit amounts; either bound may be left out
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AmountLimits {
+    pub min_amount: Option<String>,
+    pub max_amount: Option<String>,
+}
+
+/// Operator-tunable server settings.
+///
+/// Loaded from the JSON file named by `FACILITATOR_CONFIG_PATH`; fields the
+/// file omits keep their defaults (`contract_hash` falls back to
+/// `CONTRACT_HASH`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FacilitatorConfig {
+    pub contract_hash: String,
+    pub network: String,
+    pub supported_tokens: Vec<String>,
+    pub fee_rates: FeeRates,
+    /// Gas payment attached to relayed deploys that don't specify one
+    pub default_gas_payment_motes: u64,
+    /// Per-entry-point overrides of `default_gas_payment_motes`, keyed by
+    /// the contract entry point the relayed deploy calls
+    pub entry_point_gas_payment_motes: BTreeMap<String, u64>,
+    /// Smallest gas payment accepted for a relayed deploy; anything lower
+    /// would run out of gas and burn the relayer's funds
+    pub min_gas_payment_motes: u64,
+    /// Largest number of claims a single fee estimate may cover
+    pub max_batch_size: u32,
+    /// Motes per base unit of each CEP-18 fee token, keyed by
+    /// `contract-<hex>`/`hash-<hex>`; tokens listed here can be picked
+    /// automatically as the fee token
+    pub fee_token_rates: BTreeMap<String, f64>,
+    /// Senders (`account-hash-<hex>`) whose payments are refused
+    pub sender_denylist: Vec<String>,
+    /// When set, only payments from these senders are accepted
+    pub sender_allowlist: Option<Vec<String>>,
+    /// Claim amount limits per token, keyed by `contract-<hex>`/`hash-<hex>`
+    pub token_amount_limits: BTreeMap<String, AmountLimits>,
+}
+
+impl Default for FacilitatorConfig {
+    fn default() -> Self {
+        Self {
+            contract_hash: std::env::var("CONTRACT_HASH").unwrap_or_else(|_| DEFAULT_CONTRACT_HASH.to_string()),
+            network: "casper-custom".to_string(),
+            supported_tokens: vec!["CSPR".to_string()],
+            fee_rates: FeeRates {
+                base_rate: 100000000, // 0.1 CSPR
+                instruction_rate: 10000000, // 0.01 CSPR per instruction
+                priority_multiplier: 1.5,
+            },
+            default_gas_payment_motes: 2_500_000_000, // 2.5 CSPR
+            entry_point_gas_payment_motes: BTreeMap::new(),
+            min_gas_payment_motes: 100_000_000, // 0.1 CSPR
+            max_batch_size: 50,
+            fee_token_rates: BTreeMap::new(),
+            sender_denylist: Vec::new(),
+            sender_allowlist: None,
+            token_amount_limits: BTreeMap::new(),
+        }
+    }
+}
+
+impl FacilitatorConfig {
+    /// Path of the config file, if one is configured
+    pub fn path() -> Option<String> {
+        std::env::var("FACILITATOR_CONFIG_PATH").ok()
+    }
+
+    /// Load and validate the configuration; defaults when no file is configured
+    pub fn load() -> Result<Self, ConfigError> {
+        let config = match Self::path() {
+            Some(path) => Self::from_file(&path)?,
+            None => Self::default(),
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn from_file(path: &str) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path).map_err(|e| ConfigError::Io(e.to_string()))?;
+        serde_json::from_str(&contents).map_err(|e| ConfigError::Parse(e.to_string()))
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.contract_hash.len() != 64 || hex::decode(&self.contract_hash).is_err() {
+            return Err(ConfigError::Invalid("contract_hash must be 64 hex characters".to_string()));
+        }
+        if self.network.is_empty() {
+            return Err(ConfigError::Invalid("network must not be empty".to_string()));
+        }
+        if self.supported_tokens.is_empty() {
+            return Err(ConfigError::Invalid("supported_tokens must not be empty".to_string()));
+        }
+        if self.fee_rates.base_rate == 0 {
+            return Err(ConfigError::Invalid("fee_rates.base_rate must be positive".to_string()));
+        }
+        if !self.fee_rates.priority_multiplier.is_finite() || self.fee_rates.priority_multiplier < 0.0 {
+            return Err(ConfigError::Invalid(
+                "fee_rates.priority_multiplier must be a non-negative number".to_string(),
+            ));
+        }
+        if self.default_gas_payment_motes < self.min_gas_payment_motes {
+            return Err(ConfigError::Invalid(
+                "default_gas_payment_motes must not be below min_gas_payment_motes".to_string(),
+            ));
+        }
+        for (entry_point, motes) in &self.entry_point_gas_payment_motes {
+            if *motes < self.min_gas_payment_motes {
+                return Err(ConfigError::Invalid(format!(
+                    "entry_point_gas_payment_motes.{} must not be below min_gas_payment_motes",
+                    entry_point
+                )));
+            }
+        }
+        if self.max_batch_size == 0 {
+            return Err(ConfigError::Invalid("max_batch_size must be positive".to_string()));
+        }
+        for (token, rate) in &self.fee_token_rates {
+            if let Err(e) = hashes::parse_contract_hash(token) {
+                return Err(ConfigError::Invalid(format!("fee_token_rates key {}: {}", token, e)));
+            }
+            if !rate.is_finite() || *rate <= 0.0 {
+                return Err(ConfigError::Invalid(format!("fee_token_rates.{} must be positive", token)));
+            }
+        }
+        for (token, limits) in &self.token_amount_limits {
+            if let Err(e) = hashes::parse_contract_hash(token) {
+                return Err(ConfigError::Invalid(format!("token_amount_limits key {}: {}", token, e)));
+            }
+            let bound = |amount: &Option<String>, name: &str| match amount.as_deref().map(units::parse_u256_amount) {
+                Some(Err(e)) => Err(ConfigError::Invalid(format!("token_amount_limits.{}.{}: {}", token, name, e))),
+                Some(Ok(amount)) => Ok(Some(amount)),
+                None => Ok(None),
+            };
+            let min_amount = bound(&limits.min_amount, "min_amount")?;
+            let max_amount = bound(&limits.max_amount, "max_amount")?;
+            if let (Some(min_amount), Some(max_amount)) = (min_amount, max_amount) {
+                if units::cmp_amounts(&min_amount, &max_amount).is_gt() {
+                    return Err(ConfigError::Invalid(format!(
+                        "token_amount_limits.{}: min_amount exceeds max_amount",
+                        token
+                    )));
+                }
+            }
+        }
+        for sender in self.sender_denylist.iter().chain(self.sender_allowlist.iter().flatten()) {
+            if let Err(e) = hashes::parse_account_hash(sender) {
+                return Err(ConfigError::Invalid(format!("sender list entry {}: {}", sender, e)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Gas payment for a relayed deploy calling `entry_point` when the
+    /// request doesn't name one
+    pub fn default_gas_payment_for(&self, entry_point: Option<&str>) -> u64 {
+        entry_point
+            .and_then(|entry_point| self.entry_point_gas_payment_motes.get(entry_point))
+            .copied()
+            .unwrap_or(self.default_gas_payment_motes)
+    }
+
+    /// Whether payments from `sender` are refused by the sender lists; an
+    /// unknown sender (`None`) is only refused when an allowlist is set
+    pub fn sender_blocked(&self, sender: Option<&AccountHash>) -> bool {
+        let listed = |list: &[String]| {
+            sender.is_some_and(|sender| {
+                list.iter()
+                    .any(|entry| hashes::parse_account_hash(entry).as_ref() == Ok(sender))
+            })
+        };
+        if listed(&self.sender_denylist) {
+            return true;
+        }
+        match &self.sender_allowlist {
+            Some(allowlist) => !listed(allowlist),
+            None => false,
+        }
+    }
+
+    /// Why a claim of `amount` (canonical, as `units::parse_u256_amount`
+    /// returns it) in `token` falls outside the token's configured limits,
+    /// if it does
+    pub fn amount_out_of_range(&self, token: &ContractHash, amount: &str) -> Option<String> {
+        let limits = self
+            .token_amount_limits
+            .iter()
+            .find(|(key, _)| hashes::parse_contract_hash(key).as_ref() == Ok(token))
+            .map(|(_, limits)| limits)?;
+        // Limits were checked by `validate`
+        let bound = |bound: &Option<String>| bound.as_deref().and_then(|b| units::parse_u256_amount(b).ok());
+        if let Some(min_amount) = bound(&limits.min_amount) {
+            if units::cmp_amounts(amount, &min_amount).is_lt() {
+                return Some(format!("amount {} is below the minimum of {}", amount, min_amount));
+            }
+        }
+        if let Some(max_amount) = bound(&limits.max_amount) {
+            if units::cmp_amounts(amount, &max_amount).is_gt() {
+                return Some(format!("amount {} is above the maximum of {}", amount, max_amount));
+            }
+        }
+        None
+    }
+
+    /// Stable hex Blake2b-256 of the configuration.
+    ///
+    /// Going through `serde_json::Value` sorts object keys, so the hash only
+    /// changes when a value does.
+    pub fn hash(&self) -> String {
+        let value = serde_json::to_value(self).unwrap_or_default();
+        hex::encode(message::message_hash(&value.to_string()))
+    }
+}
+
+/// Copy of the current configuration, so handlers never hold the lock
+pub fn snapshot(config: &SharedConfig) -> FacilitatorConfig {
+    match config.read() {
+        Ok(current) => current.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+/// Replace `config` with the file at `path` if it parses and validates,
+/// returning the new config hash; on error `config` is left unchanged
+pub fn reload(config: &SharedConfig, path: &str) -> Result<String, ConfigError> {
+    let new_config = FacilitatorConfig::from_file(path)?;
+    new_config.validate()?;
+    let config_hash = new_config.hash();
+    match config.write() {
+        Ok(mut current) => *current = new_config,
+        Err(poisoned) => *poisoned.into_inner() = new_config,
+    }
+    Ok(config_hash)
+}
+
+/// Re-read the config file on every SIGHUP.
+///
+/// A new configuration replaces the shared one only if it parses and
+/// validates; otherwise the running configuration is kept.
+#[cfg(unix)]
+pub fn spawn_reload_on_sighup(config: SharedConfig) {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    tokio::spawn(async move {
+        let mut hangups = match signal(SignalKind::hangup()) {
+            Ok(hangups) => hangups,
+            Err(e) => {
+                log::error!("Failed to install SIGHUP handler: {}", e);
+                return;
+            }
+        };
+
+        while hangups.recv().await.is_some() {
+            let path = match FacilitatorConfig::path() {
+                Some(path) => path,
+                None => {
+                    log::warn!("Received SIGHUP but FACILITATOR_CONFIG_PATH is not set; nothing to reload");
+                    continue;
+                }
+            };
+
+            match reload(&config, &path) {
+                Ok(config_hash) => {
+                    log::info!("Reloaded config from {} (config_hash {})", path, config_hash);
+                }
+                Err(e) => {
+                    log::error!("Config reload from {} rejected, keeping current config: {}", path, e);
+                }
+            }
+        }
+    });
+}
+
+#[cfg(not(unix))]
+pub fn spawn_reload_on_sighup(_config: SharedConfig) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLOCKED: &str = "account-hash-0101010101010101010101010101010101010101010101010101010101010101";
+    const PERMITTED: &str = "account-hash-0202020202020202020202020202020202020202020202020202020202020202";
+
+    fn account(value: &str) -> AccountHash {
+        hashes::parse_account_hash(value).unwrap()
+    }
+
+    #[test]
+    fn denylisted_senders_are_blocked() {
+        let config = FacilitatorConfig {
+            sender_denylist: vec![BLOCKED.to_string()],
+            ..FacilitatorConfig::default()
+        };
+        assert!(config.validate().is_ok());
+        assert!(config.sender_blocked(Some(&account(BLOCKED))));
+        assert!(!config.sender_blocked(Some(&account(PERMITTED))));
+        assert!(!config.sender_blocked(None));
+    }
+
+    #[test]
+    fn allowlist_admits_only_listed_senders() {
+        let config = FacilitatorConfig {
+            sender_allowlist: Some(vec![PERMITTED.to_string(), BLOCKED.to_string()]),
+            sender_denylist: vec![BLOCKED.to_string()],
+            ..FacilitatorConfig::default()
+        };
+        assert!(!config.sender_blocked(Some(&account(PERMITTED))));
+        assert!(config.sender_blocked(Some(&account(BLOCKED))));
+        assert!(config.sender_blocked(None));
+
+        let unlisted = "account-hash-0303030303030303030303030303030303030303030303030303030303030303";
+        assert!(config.sender_blocked(Some(&account(unlisted))));
+    }
+
+    #[test]
+    fn token_amount_limits_bound_claims_in_that_token() {
+        const TOKEN: &str = "hash-0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a";
+        const OTHER: &str = "hash-0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b";
+        let limits = |min_amount: Option<&str>, max_amount: Option<&str>| FacilitatorConfig {
+            token_amount_limits: BTreeMap::from([(
+                TOKEN.to_string(),
+                AmountLimits {
+                    min_amount: min_amount.map(str::to_string),
+                    max_amount: max_amount.map(str::to_string),
+                },
+            )]),
+            ..FacilitatorConfig::default()
+        };
+        let token = hashes::parse_contract_hash(TOKEN).unwrap();
+
+        let config = limits(Some("1000"), Some("5000000"));
+        assert!(config.validate().is_ok());
+        assert_eq!(config.amount_out_of_range(&token, "1000"), None);
+        assert_eq!(config.amount_out_of_range(&token, "5000000"), None);
+        assert!(config.amount_out_of_range(&token, "999").unwrap().contains("below the minimum"));
+        assert!(config.amount_out_of_range(&token, "5000001").unwrap().contains("above the maximum"));
+        assert_eq!(config.amount_out_of_range(&hashes::parse_contract_hash(OTHER).unwrap(), "1"), None);
+
+        // Either bound may be left out
+        assert_eq!(limits(None, Some("10")).amount_out_of_range(&token, "0"), None);
+        assert_eq!(limits(Some("10"), None).amount_out_of_range(&token, units::U256_MAX_DECIMAL), None);
+
+        assert!(limits(Some("10"), Some("9")).validate().is_err());
+        assert!(limits(Some("-1"), None).validate().is_err());
+    }
+
+    #[test]
+    fn malformed_sender_entries_are_rejected() {
+        let config = FacilitatorConfig {
+            sender_denylist: vec!["0101".to_string()],
+            ..FacilitatorConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn entry_points_fall_back_to_the_default_gas_payment() {
+        let config = FacilitatorConfig {
+            entry_point_gas_payment_motes: BTreeMap::from([("claim_batch".to_string(), 10_000_000_000)]),
+            ..FacilitatorConfig::default()
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(config.default_gas_payment_for(Some("claim_batch")), 10_000_000_000);
+        assert_eq!(config.default_gas_payment_for(Some("claim")), config.default_gas_payment_motes);
+        assert_eq!(config.default_gas_payment_for(None), config.default_gas_payment_motes);
+    }
+
+    #[test]
+    fn entry_point_gas_payment_below_minimum_is_rejected() {
+        let config = FacilitatorConfig {
+            entry_point_gas_payment_motes: BTreeMap::from([("claim".to_string(), 1)]),
+            ..FacilitatorConfig::default()
+        };
+        assert!(matches!(config.validate(), Err(ConfigError::Invalid(_))));
+    }
+
+    #[test]
+    fn equal_configs_hash_the_same() {
+        let config = FacilitatorConfig::default();
+        assert_eq!(config.hash(), config.clone().hash());
+        assert_eq!(config.hash().len(), 64);
+
+        // Key order in the file does not matter
+        let reordered: FacilitatorConfig =
+            serde_json::from_str(r#"{"max_batch_size": 50, "network": "casper-custom"}"#).unwrap();
+        assert_eq!(reordered.hash(), config.hash());
+    }
+
+    #[test]
+    fn changing_a_field_changes_the_hash() {
+        let config = FacilitatorConfig::default();
+        let changed = [
+            FacilitatorConfig { network: "casper-test".to_string(), ..config.clone() },
+            FacilitatorConfig { max_batch_size: 51, ..config.clone() },
+            FacilitatorConfig { sender_denylist: vec![BLOCKED.to_string()], ..config.clone() },
+            FacilitatorConfig {
+                fee_rates: FeeRates { priority_multiplier: 2.0, ..config.fee_rates.clone() },
+                ..config.clone()
+            },
+        ];
+        for other in &changed {
+            assert_ne!(other.hash(), config.hash(), "{:?}", other);
+        }
+    }
+
+    /// Write `contents` to a file unique to this test and return its path
+    fn config_file(name: &str, contents: &str) -> String {
+        let path = std::env::temp_dir().join(format!("facilitator-{}-{}.json", name, std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn reload_replaces_the_config() {
+        let config: SharedConfig = Arc::new(RwLock::new(FacilitatorConfig::default()));
+        let path = config_file("reload-ok", r#"{"max_batch_size": 10, "network": "casper-test"}"#);
+
+        let config_hash = reload(&config, &path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let current = snapshot(&config);
+        assert_eq!(current.max_batch_size, 10);
+        assert_eq!(current.network, "casper-test");
+        assert_eq!(config_hash, current.hash());
+    }
+
+    #[test]
+    fn invalid_reload_keeps_the_running_config() {
+        let original = FacilitatorConfig::default();
+        let config: SharedConfig = Arc::new(RwLock::new(original.clone()));
+
+        let invalid = config_file("reload-invalid", r#"{"max_batch_size": 0}"#);
+        assert!(matches!(reload(&config, &invalid), Err(ConfigError::Invalid(_))));
+        std::fs::remove_file(&invalid).unwrap();
+
+        let malformed = config_file("reload-malformed", "{ not json");
+        assert!(matches!(reload(&config, &malformed), Err(ConfigError::Parse(_))));
+        std::fs::remove_file(&malformed).unwrap();
+
+        assert!(matches!(reload(&config, "/nonexistent/facilitator.json"), Err(ConfigError::Io(_))));
+        assert_eq!(snapshot(&config), original);
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::node::{NodeClient, NodeError};
+
+/// Serves last-known node data while the node is unreachable.
+///
+/// When enabled, read-only endpoints fall back to the most recent value
+/// successfully read from the node and flag their response as stale, and
+/// state-changing endpoints refuse work until the node answers again.
+/// When disabled, node failures are reported as before.
+#[derive(Debug, Default)]
+pub struct DegradedMode {
+    enabled: bool,
+    pause_states: Mutex<HashMap<String, (bool, Option<String>)>>,
+    token_display_infos: Mutex<HashMap<String, (u8, String)>>,
+}
+
+impl DegradedMode {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            ..Self::default()
+        }
+    }
+
+    /// Enabled by `FACILITATOR_DEGRADED_MODE=1` (or `true`)
+    pub fn from_env() -> Self {
+        let enabled = std::env::var("FACILITATOR_DEGRADED_MODE")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        Self::new(enabled)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// The contract's pause state, and whether it is a last-known value
+    pub async fn pause_state(
+        &self,
+        node: &NodeClient,
+        contract_hash: &str,
+    ) -> Result<((bool, Option<String>), bool), NodeError> {
+        let result = node.get_pause_state(contract_hash).await;
+        self.fresh_or_last_known(&self.pause_states, contract_hash, result)
+    }
+
+    /// A token's `(decimals, symbol)`, and whether it is a last-known value
+    pub async fn token_display_info(
+        &self,
+        node: &NodeClient,
+        contract_hash: &str,
+    ) -> Result<((u8, String), bool), NodeError> {
+        let result = node.get_token_display_info(contract_hash).await;
+        self.fresh_or_last_known(&self.token_display_infos, contract_hash, result)
+    }
+
+    /// Check the node answers before accepting state-changing work.
+    ///
+    /// Always passes when degraded mode is disabled.
+    pub async fn check_node_for_write(&self, node: &NodeClient) -> Result<(), NodeError> {
+        if !self.enabled {
+            return Ok(());
+        }
+        match node.get_state_root_hash().await {
+            Err(e) if e.is_unreachable() => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    /// Remember a fresh value, or fall back to the remembered one when the
+    /// node is unreachable and degraded mode is enabled
+    fn fresh_or_last_known<T: Clone>(
+        &self,
+        last_known: &Mutex<HashMap<String, T>>,
+        key: &str,
+        result: Result<T, NodeError>,
+    ) -> Result<(T, bool), NodeError> {
+        let mut last_known = last_known.lock().unwrap();
+        match result {
+            Ok(value) => {
+                last_known.insert(key.to_string(), value.clone());
+                Ok((value, false))
+            }
+            Err(e) if self.enabled && e.is_unreachable() => match last_known.get(key) {
+                Some(value) => Ok((value.clone(), true)),
+                None => Err(e),
+            },
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::unreachable_node;
+
+    const CONTRACT: &str = "6a545487ba47c62bdf02f68a9d8ada590fef2a1d28778dd5b346d63927e61b4a";
+
+    #[tokio::test]
+    async fn read_only_data_is_served_stale_while_node_is_down() {
+        let degraded = DegradedMode::new(true);
+        degraded
+            .pause_states
+            .lock()
+            .unwrap()
+            .insert(CONTRACT.to_string(), (true, Some("maintenance".to_string())));
+
+        let (pause_state, stale) = degraded.pause_state(&unreachable_node(), CONTRACT).await.unwrap();
+        assert_eq!(pause_state, (true, Some("maintenance".to_string())));
+        assert!(stale);
+
+        // Nothing remembered for this token yet
+        let result = degraded.token_display_info(&unreachable_node(), CONTRACT).await;
+        assert!(matches!(result, Err(NodeError::Transport(_))));
+    }
+
+    #[tokio::test]
+    async fn node_failures_are_reported_when_disabled() {
+        let degraded = DegradedMode::new(false);
+        degraded
+            .pause_states
+            .lock()
+            .unwrap()
+            .insert(CONTRACT.to_string(), (false, None));
+
+        let result = degraded.pause_state(&unreachable_node(), CONTRACT).await;
+        assert!(matches!(result, Err(NodeError::Transport(_))));
+        assert!(degraded.check_node_for_write(&unreachable_node()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn writes_are_refused_while_node_is_down() {
+        let degraded = DegradedMode::new(true);
+        let result = degraded.check_node_for_write(&unreachable_node()).await;
+        assert!(matches!(result, Err(NodeError::Transport(_))));
+    }
+}
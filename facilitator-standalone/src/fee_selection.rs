@@ -0,0 +1,221 @@
+use serde_json::Value;
+
+use crate::config::FacilitatorConfig;
+use crate::hashes::{self, AccountHash, ContractHash};
+use crate::node::{NodeClient, NodeError};
+
+/// Fee in a token's base units for a fee of `fee_motes`, rounded up
+pub fn fee_in_token(fee_motes: u64, motes_per_unit: f64) -> Option<u64> {
+    let amount = (fee_motes as f64 / motes_per_unit).ceil();
+    if amount.is_finite() && amount >= 0.0 && amount < u64::MAX as f64 {
+        Some(amount as u64)
+    } else {
+        None
+    }
+}
+
+/// Instruction fee of a deploy running `batch_size` claims
+pub fn batch_instruction_fee(per_claim_fee: u64, batch_size: u32) -> u64 {
+    per_claim_fee.saturating_mul(u64::from(batch_size))
+}
+
+/// Cost in motes of one claim from speculative execution of `claim_deploy`.
+///
+/// Returns `None` when no deploy was given or the node could not run it
+/// (speculative execution is off on most public nodes), in which case the
+/// caller falls back to the rate-based estimate.
+pub async fn speculative_claim_cost(node: &NodeClient, claim_deploy: Option<&Value>) -> Option<u64> {
+    match node.speculative_exec_cost(claim_deploy?).await {
+        Ok(cost) => Some(cost),
+        Err(e) => {
+            log::warn!("Speculative execution unavailable, using the rate-based estimate: {}", e);
+            None
+        }
+    }
+}
+
+/// Configured motes-per-unit rate for a fee token, if any
+pub fn token_rate(config: &FacilitatorConfig, token: &ContractHash) -> Option<f64> {
+    config
+        .fee_token_rates
+        .iter()
+        .find(|(key, _)| hashes::parse_contract_hash(key).as_ref() == Ok(token))
+        .map(|(_, rate)| *rate)
+}
+
+/// A fee token with the fee it would charge, in its base units
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct FeeCandidate {
+    token: ContractHash,
+    fee: u64,
+    motes_per_unit: f64,
+}
+
+impl FeeCandidate {
+    /// What the fee is worth in motes. Base units of different tokens are
+    /// not comparable, and rounding the fee up costs more in coarser tokens.
+    fn fee_motes(&self) -> f64 {
+        self.fee as f64 * self.motes_per_unit
+    }
+}
+
+/// Each configured fee token with its fee for `fee_motes`, in config order;
+/// tokens whose fee cannot be converted are left out
+fn fee_candidates(config: &FacilitatorConfig, fee_motes: u64) -> Vec<FeeCandidate> {
+    config
+        .fee_token_rates
+        .iter()
+        .filter_map(|(token, rate)| {
+            Some(FeeCandidate {
+                token: hashes::parse_contract_hash(token).ok()?,
+                fee: fee_in_token(fee_motes, *rate)?,
+                motes_per_unit: *rate,
+            })
+        })
+        .collect()
+}
+
+/// Candidate with the fee worth the fewest motes among those whose balance
+/// (same index in `balances`) covers their fee; ties go to the earlier
+/// candidate
+fn cheapest_covered(candidates: &[FeeCandidate], balances: &[u128]) -> Option<(ContractHash, u64)> {
+    candidates
+        .iter()
+        .zip(balances)
+        .filter(|(candidate, balance)| **balance >= u128::from(candidate.fee))
+        .map(|(candidate, _)| candidate)
+        .min_by(|a, b| a.fee_motes().total_cmp(&b.fee_motes()))
+        .map(|candidate| (candidate.token, candidate.fee))
+}
+
+/// Pick the configured fee token whose fee is worth the fewest motes among
+/// those the user can cover.
+///
+/// Only tokens in `fee_token_rates` are considered, and a token qualifies
+/// when the user's balance is at least the converted fee. Fees are compared
+/// at their configured rates, so only the rounding up to whole base units
+/// separates tokens. The balances are read concurrently. Ties go to the
+/// token listed first. Returns `None` when no token qualifies.
+pub async fn select_cheapest_fee_token(
+    node: &NodeClient,
+    config: &FacilitatorConfig,
+    user: &AccountHash,
+    fee_motes: u64,
+) -> Result<Option<(ContractHash, u64)>, NodeError> {
+    let candidates = fee_candidates(config, fee_motes);
+    let user = &user.to_hex();
+    let balances = futures_util::future::try_join_all(candidates.iter().map(|candidate| async move {
+        node.get_token_balance(&candidate.token.to_hex(), user).await
+    }))
+    .await?;
+
+    Ok(cheapest_covered(&candidates, &balances))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{node_answering_with, unreachable_node};
+    use std::collections::BTreeMap;
+
+    const TOKEN_A: &str = "hash-0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a";
+    const TOKEN_B: &str = "hash-0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b";
+
+    /// Token A is worth 1_000 motes per unit, token B 4_000, so a fee costs
+    /// four times fewer units of B
+    fn config_with_two_tokens() -> FacilitatorConfig {
+        FacilitatorConfig {
+            fee_token_rates: BTreeMap::from([(TOKEN_A.to_string(), 1_000.0), (TOKEN_B.to_string(), 4_000.0)]),
+            ..FacilitatorConfig::default()
+        }
+    }
+
+    fn token(value: &str) -> ContractHash {
+        hashes::parse_contract_hash(value).unwrap()
+    }
+
+    #[test]
+    fn cheapest_held_token_is_picked() {
+        let candidates = fee_candidates(&config_with_two_tokens(), 1_000_000);
+        let fees: Vec<_> = candidates.iter().map(|c| (c.token, c.fee)).collect();
+        assert_eq!(fees, vec![(token(TOKEN_A), 1_000), (token(TOKEN_B), 250)]);
+        // Both fees are worth 1_000_000 motes, so the first token wins
+        assert_eq!(cheapest_covered(&candidates, &[5_000, 5_000]), Some((token(TOKEN_A), 1_000)));
+
+        // Rounded up to whole units, 251 B (1_004_000 motes) costs more than
+        // 1_001 A (1_001_000 motes), though it is fewer units
+        let candidates = fee_candidates(&config_with_two_tokens(), 1_000_500);
+        assert_eq!(cheapest_covered(&candidates, &[5_000, 5_000]), Some((token(TOKEN_A), 1_001)));
+        assert_eq!(cheapest_covered(&candidates, &[0, 5_000]), Some((token(TOKEN_B), 251)));
+    }
+
+    #[test]
+    fn fees_are_compared_in_motes_not_base_units() {
+        // A unit of B is worth 2_000_000 motes, so a 1_000 mote fee rounds
+        // up to one whole B: one base unit, but worth 2_000 times the fee
+        let config = FacilitatorConfig {
+            fee_token_rates: BTreeMap::from([(TOKEN_A.to_string(), 1.0), (TOKEN_B.to_string(), 2_000_000.0)]),
+            ..FacilitatorConfig::default()
+        };
+        let candidates = fee_candidates(&config, 1_000);
+        let fees: Vec<_> = candidates.iter().map(|c| (c.token, c.fee)).collect();
+        assert_eq!(fees, vec![(token(TOKEN_A), 1_000), (token(TOKEN_B), 1)]);
+        assert_eq!(cheapest_covered(&candidates, &[1_000, 1]), Some((token(TOKEN_A), 1_000)));
+    }
+
+    #[test]
+    fn tokens_without_enough_balance_are_skipped() {
+        let candidates = fee_candidates(&config_with_two_tokens(), 1_000_000);
+        assert_eq!(cheapest_covered(&candidates, &[5_000, 0]), Some((token(TOKEN_A), 1_000)));
+        assert_eq!(cheapest_covered(&candidates, &[1_000, 249]), Some((token(TOKEN_A), 1_000)));
+    }
+
+    #[test]
+    fn no_token_is_picked_when_none_is_covered() {
+        let candidates = fee_candidates(&config_with_two_tokens(), 1_000_000);
+        assert_eq!(cheapest_covered(&candidates, &[999, 249]), None);
+        assert_eq!(cheapest_covered(&[], &[]), None);
+    }
+
+    /// A node that answers every request with `body`
+    async fn node_answering(body: &'static str) -> NodeClient {
+        node_answering_with(move || body.to_string()).await
+    }
+
+    #[test]
+    fn batch_fee_scales_with_claims() {
+        assert_eq!(batch_instruction_fee(10_000_000, 1), 10_000_000);
+        assert_eq!(batch_instruction_fee(10_000_000, 20), 20 * batch_instruction_fee(10_000_000, 1));
+        assert_eq!(batch_instruction_fee(u64::MAX, 2), u64::MAX);
+    }
+
+    #[tokio::test]
+    async fn speculative_cost_is_used_when_available() {
+        let node = node_answering(
+            r#"{"jsonrpc":"2.0","id":1,"result":{"execution_result":{"Success":{"cost":"1234567"}}}}"#,
+        )
+        .await;
+        assert_eq!(speculative_claim_cost(&node, Some(&serde_json::json!({}))).await, Some(1_234_567));
+    }
+
+    #[tokio::test]
+    async fn rate_estimate_is_used_without_speculative_execution() {
+        let node = node_answering(r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32601,"message":"Method not found"}}"#).await;
+        assert_eq!(speculative_claim_cost(&node, Some(&serde_json::json!({}))).await, None);
+
+        let unreachable = unreachable_node();
+        assert_eq!(speculative_claim_cost(&unreachable, Some(&serde_json::json!({}))).await, None);
+        assert_eq!(speculative_claim_cost(&unreachable, None).await, None);
+    }
+
+    #[tokio::test]
+    async fn no_configured_token_needs_no_balance_read() {
+        let node = unreachable_node();
+        let user = hashes::AccountHash([1u8; 32]);
+        let selected = select_cheapest_fee_token(&node, &FacilitatorConfig::default(), &user, 1_000_000).await;
+        assert!(matches!(selected, Ok(None)));
+
+        let selected = select_cheapest_fee_token(&node, &config_with_two_tokens(), &user, 1_000_000).await;
+        assert!(matches!(selected, Err(NodeError::Transport(_))));
+    }
+}
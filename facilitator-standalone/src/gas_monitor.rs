@@ -0,0 +1,152 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::node::NodeClient;
+
+const DEFAULT_MIN_BALANCE_MOTES: u128 = 100_000_000_000; // 100 CSPR
+const DEFAULT_CHECK_INTERVAL_SECS: u64 = 60;
+
+/// Settings for watching the relayer account that pays gas for relayed deploys
+#[derive(Debug, Clone)]
+pub struct GasMonitorConfig {
+    pub relayer_account: String,
+    pub min_balance_motes: u128,
+    pub check_interval: Duration,
+    pub pause_relaying_when_low: bool,
+}
+
+impl GasMonitorConfig {
+    /// Load the monitor settings from the environment.
+    ///
+    /// Returns `None` when `FACILITATOR_RELAYER_ACCOUNT` is not set, in which
+    /// case no balance monitoring is performed.
+    pub fn from_env() -> Option<Self> {
+        let relayer_account = std::env::var("FACILITATOR_RELAYER_ACCOUNT").ok()?;
+
+        let min_balance_motes = std::env::var("FACILITATOR_MIN_RELAYER_BALANCE_MOTES")
+            .ok()
+            .and_then(|v| v.parse::<u128>().ok())
+            .unwrap_or(DEFAULT_MIN_BALANCE_MOTES);
+
+        let check_interval_secs = std::env::var("FACILITATOR_BALANCE_CHECK_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_CHECK_INTERVAL_SECS);
+
+        let pause_relaying_when_low = std::env::var("FACILITATOR_PAUSE_ON_LOW_BALANCE")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+
+        Some(Self {
+            relayer_account,
+            min_balance_motes,
+            check_interval: Duration::from_secs(check_interval_secs),
+            pause_relaying_when_low,
+        })
+    }
+}
+
+/// Shared relayer health flag read by the relaying handlers
+#[derive(Debug, Default)]
+pub struct RelayerStatus {
+    balance_low: AtomicBool,
+    pause_relaying_when_low: AtomicBool,
+}
+
+impl RelayerStatus {
+    pub fn is_balance_low(&self) -> bool {
+        self.balance_low.load(Ordering::Relaxed)
+    }
+
+    /// Whether relaying endpoints should currently refuse work (503)
+    pub fn is_relaying_paused(&self) -> bool {
+        self.is_balance_low() && self.pause_relaying_when_low.load(Ordering::Relaxed)
+    }
+
+    /// Record the outcome of a balance check against the configured threshold
+    pub fn record_balance(&self, config: &GasMonitorConfig, balance_motes: u128) -> bool {
+        let low = balance_motes < config.min_balance_motes;
+        self.pause_relaying_when_low
+            .store(config.pause_relaying_when_low, Ordering::Relaxed);
+        self.balance_low.store(low, Ordering::Relaxed);
+        low
+    }
+}
+
+/// Spawn a background task that periodically checks the relayer balance.
+///
+/// A balance under the threshold is logged at error level on every check
+/// until it is topped up; a failed node query keeps the previous status.
+pub fn spawn_gas_monitor(node: NodeClient, config: GasMonitorConfig, status: Arc<RelayerStatus>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(config.check_interval);
+        loop {
+            interval.tick().await;
+            match node.get_account_balance(&config.relayer_account).await {
+                Ok(balance) => {
+                    let was_low = status.is_balance_low();
+                    if status.record_balance(&config, balance) {
+                        log::error!(
+                            "CRITICAL: relayer {} balance {} motes is below threshold {} motes{}",
+                            config.relayer_account,
+                            balance,
+                            config.min_balance_motes,
+                            if config.pause_relaying_when_low { "; relaying paused" } else { "" }
+                        );
+                    } else if was_low {
+                        log::info!(
+                            "Relayer {} balance recovered to {} motes",
+                            config.relayer_account,
+                            balance
+                        );
+                    }
+                }
+                Err(e) => {
+                    log::warn!("Failed to check relayer balance: {}", e);
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(pause_relaying_when_low: bool) -> GasMonitorConfig {
+        GasMonitorConfig {
+            relayer_account: "account-hash-0101010101010101010101010101010101010101010101010101010101010101".to_string(),
+            min_balance_motes: 1_000,
+            check_interval: Duration::from_secs(60),
+            pause_relaying_when_low,
+        }
+    }
+
+    #[test]
+    fn balance_below_threshold_is_low() {
+        let status = RelayerStatus::default();
+        assert!(status.record_balance(&config(false), 999));
+        assert!(status.is_balance_low());
+        // Relaying continues unless pausing is enabled
+        assert!(!status.is_relaying_paused());
+
+        assert!(status.record_balance(&config(true), 0));
+        assert!(status.is_relaying_paused());
+    }
+
+    #[test]
+    fn balance_at_or_above_threshold_is_healthy() {
+        let status = RelayerStatus::default();
+        assert!(!status.record_balance(&config(true), 1_000));
+        assert!(!status.is_balance_low());
+        assert!(!status.is_relaying_paused());
+
+        status.record_balance(&config(true), 10);
+        assert!(status.is_relaying_paused());
+        // A top-up lifts the pause
+        assert!(!status.record_balance(&config(true), 5_000));
+        assert!(!status.is_relaying_paused());
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt;
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+
+pub const ACCOUNT_HASH_PREFIX: &str = "account-hash-";
+pub const CONTRACT_HASH_PREFIX: &str = "contract-";
+pub const HASH_KEY_PREFIX: &str = "hash-";
+
+#[derive(Debug, PartialEq)]
+pub enum HashParseError {
+    MissingPrefix(&'static str),
+    InvalidLength(usize),
+    InvalidHex,
+}
+
+impl fmt::Display for HashParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashParseError::MissingPrefix(prefix) => write!(f, "expected a '{}' prefix", prefix),
+            HashParseError::InvalidLength(len) => {
+                write!(f, "expected 64 hex characters after the prefix, got {}", len)
+            }
+            HashParseError::InvalidHex => write!(f, "hash contains non-hex characters"),
+        }
+    }
+}
+
+/// A 32-byte account hash parsed from `account-hash-<hex>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AccountHash(pub [u8; 32]);
+
+/// A 32-byte contract hash parsed from `contract-<hex>` or `hash-<hex>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContractHash(pub [u8; 32]);
+
+impl AccountHash {
+    pub fn to_hex(self) -> String {
+        hex::encode(self.0)
+    }
+}
+
+impl ContractHash {
+    pub fn to_hex(self) -> String {
+        hex::encode(self.0)
+    }
+}
+
+impl fmt::Display for AccountHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", ACCOUNT_HASH_PREFIX, self.to_hex())
+    }
+}
+
+impl fmt::Display for ContractHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", CONTRACT_HASH_PREFIX, self.to_hex())
+    }
+}
+
+/// Account hash of a hex public key (algorithm tag byte plus key bytes),
+/// derived as Casper does: Blake2b-256 of `<algorithm name> 0x00 <key bytes>`
+pub fn account_hash_from_public_key(public_key: &str) -> Option<AccountHash> {
+    let bytes = hex::decode(public_key).ok()?;
+    let (algorithm, key): (&[u8], &[u8]) = match bytes.split_first()? {
+        (1, key) if key.len() == 32 => (b"ed25519", key),
+        (2, key) if key.len() == 33 => (b"secp256k1", key),
+        _ => return None,
+    };
+
+    let mut hasher = Blake2b::<U32>::new();
+    hasher.update(algorithm);
+    hasher.update([0u8]);
+    hasher.update(key);
+    Some(AccountHash(hasher.finalize().into()))
+}
+
+/// Parse a formatted `account-hash-<64 hex>` string
+pub fn parse_account_hash(value: &str) -> Result<AccountHash, HashParseError> {
+    let hex_part = value
+        .strip_prefix(ACCOUNT_HASH_PREFIX)
+        .ok_or(HashParseError::MissingPrefix(ACCOUNT_HASH_PREFIX))?;
+    decode_hash(hex_part).map(AccountHash)
+}
+
+/// Parse a formatted `contract-<64 hex>` (or `hash-<64 hex>` key) string
+pub fn parse_contract_hash(value: &str) -> Result<ContractHash, HashParseError> {
+    let hex_part = value
+        .strip_prefix(CONTRACT_HASH_PREFIX)
+        .or_else(|| value.strip_prefix(HASH_KEY_PREFIX))
+        .ok_or(HashParseError::MissingPrefix(CONTRACT_HASH_PREFIX))?;
+    decode_hash(hex_part).map(ContractHash)
+}
+
+fn decode_hash(hex_part: &str) -> Result<[u8; 32], HashParseError> {
+    if hex_part.len() != 64 {
+        return Err(HashParseError::InvalidLength(hex_part.len()));
+    }
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(hex_part, &mut bytes).map_err(|_| HashParseError::InvalidHex)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_account_hash_from_public_key() {
+        let ed25519 = account_hash_from_public_key(
+            "0179b5562e8fe654f94078b112e8a98ba7901f853ae695bed7e0e3910bad049664",
+        );
+        assert_eq!(
+            ed25519.map(AccountHash::to_hex).as_deref(),
+            Some("2cafe3242874778ed07291e1205a2773e4913b920695cbd8bb5887373eff99f7")
+        );
+
+        let secp256k1 = account_hash_from_public_key(
+            "0202085fe2ca7a5758957ea811bd8e743d9cee6bc20072f1470a888c43a1091a8e8b",
+        );
+        assert_eq!(
+            secp256k1.map(AccountHash::to_hex).as_deref(),
+            Some("cf647ab8827666b940829a1732b558d57ebb88bb66708005005c15a13c128ad8")
+        );
+
+        assert_eq!(account_hash_from_public_key("03abcd"), None);
+        assert_eq!(account_hash_from_public_key("01abcd"), None);
+    }
+
+    const HEX: &str = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn parses_prefixed_hashes() {
+        let account = parse_account_hash(&format!("account-hash-{}", HEX)).unwrap();
+        assert_eq!(account.to_hex(), HEX);
+        assert_eq!(account.to_string(), format!("account-hash-{}", HEX));
+
+        let contract = parse_contract_hash(&format!("contract-{}", HEX)).unwrap();
+        assert_eq!(contract.to_hex(), HEX);
+        assert_eq!(parse_contract_hash(&format!("hash-{}", HEX)), Ok(contract));
+        assert_eq!(parse_account_hash(&format!("account-hash-{}", HEX.to_uppercase())), Ok(account));
+    }
+
+    #[test]
+    fn rejects_missing_or_wrong_prefixes() {
+        assert_eq!(parse_account_hash(HEX), Err(HashParseError::MissingPrefix(ACCOUNT_HASH_PREFIX)));
+        assert_eq!(
+            parse_account_hash(&format!("hash-{}", HEX)),
+            Err(HashParseError::MissingPrefix(ACCOUNT_HASH_PREFIX))
+        );
+        assert_eq!(parse_contract_hash(HEX), Err(HashParseError::MissingPrefix(CONTRACT_HASH_PREFIX)));
+        assert_eq!(
+            parse_contract_hash(&format!("account-hash-{}", HEX)),
+            Err(HashParseError::MissingPrefix(CONTRACT_HASH_PREFIX))
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_lengths() {
+        assert_eq!(
+            parse_account_hash(&format!("account-hash-{}", &HEX[..63])),
+            Err(HashParseError::InvalidLength(63))
+        );
+        assert_eq!(
+            parse_contract_hash(&format!("contract-{}00", HEX)),
+            Err(HashParseError::InvalidLength(66))
+        );
+        assert_eq!(parse_contract_hash("hash-"), Err(HashParseError::InvalidLength(0)));
+    }
+
+    #[test]
+    fn rejects_non_hex_characters() {
+        let non_hex = format!("{}g", &HEX[..63]);
+        assert_eq!(parse_account_hash(&format!("account-hash-{}", non_hex)), Err(HashParseError::InvalidHex));
+        assert_eq!(parse_contract_hash(&format!("contract-{}", non_hex)), Err(HashParseError::InvalidHex));
+    }
+}
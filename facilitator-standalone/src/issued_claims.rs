@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Most claim messages remembered at once; past this the earliest issued
+/// is forgotten first
+const MAX_ISSUED_CLAIMS: usize = 10_000;
+
+/// When this server issued each claim message, keyed by message hash.
+///
+/// The claim message layout is fixed by the on-chain permit, so it cannot
+/// carry an issue time of its own. Instead `/claim_message` records the
+/// node block time it first handed a message out at, and `/verify_payment`
+/// measures a signature's age from there, rebuilding the message from the
+/// signed fields. Asking for the same message again keeps the original
+/// time, so an old signature cannot be freshened.
+///
+/// Records live in memory until the message's deadline passes: a restarted
+/// server, or another instance behind the same load balancer, does not
+/// know them, and their payers must request a new claim message.
+#[derive(Debug)]
+pub struct IssuedClaims {
+    max_age_secs: Option<u64>,
+    /// Message hash to `(issued_at, deadline)`, in node block time (ms)
+    issued: Mutex<HashMap<[u8; 32], (u64, u64)>>,
+}
+
+impl IssuedClaims {
+    pub fn new(max_age_secs: Option<u64>) -> Self {
+        Self {
+            max_age_secs,
+            issued: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Limit from `FACILITATOR_MAX_SIG_AGE_SECS`; unset disables the check.
+    /// This only narrows the HTTP-layer replay window; the on-chain
+    /// `deadline` still governs validity.
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var("FACILITATOR_MAX_SIG_AGE_SECS")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok()),
+        )
+    }
+
+    /// How long after issue a signature is accepted, if limited
+    pub fn max_age_secs(&self) -> Option<u64> {
+        self.max_age_secs
+    }
+
+    /// Record that the message hashing to `message_hash` was issued at
+    /// `now_ms`, and return when it was first issued
+    pub fn issue(&self, message_hash: [u8; 32], now_ms: u64, deadline: u64) -> u64 {
+        if self.max_age_secs.is_none() {
+            return now_ms;
+        }
+        let mut issued = self.issued.lock().unwrap();
+        if let Some((issued_at, _)) = issued.get(&message_hash) {
+            return *issued_at;
+        }
+
+        issued.retain(|_, (_, deadline)| *deadline >= now_ms);
+        if issued.len() >= MAX_ISSUED_CLAIMS {
+            let earliest = issued.iter().min_by_key(|(_, (issued_at, _))| *issued_at).map(|(hash, _)| *hash);
+            if let Some(earliest) = earliest {
+                issued.remove(&earliest);
+            }
+        }
+        issued.insert(message_hash, (now_ms, deadline));
+        now_ms
+    }
+
+    /// When the message hashing to `message_hash` was first issued, if
+    /// this server issued it
+    pub fn issued_at(&self, message_hash: &[u8; 32]) -> Option<u64> {
+        self.issued.lock().unwrap().get(message_hash).map(|(issued_at, _)| *issued_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reissuing_a_message_keeps_its_first_issue_time() {
+        let claims = IssuedClaims::new(Some(60));
+        assert_eq!(claims.issue([1; 32], 1_000, 300_000), 1_000);
+        assert_eq!(claims.issue([1; 32], 200_000, 300_000), 1_000);
+        assert_eq!(claims.issued_at(&[1; 32]), Some(1_000));
+        assert_eq!(claims.issued_at(&[2; 32]), None);
+    }
+
+    #[test]
+    fn messages_are_forgotten_once_their_deadline_passes() {
+        let claims = IssuedClaims::new(Some(60));
+        claims.issue([1; 32], 1_000, 5_000);
+        claims.issue([2; 32], 6_000, 300_000);
+        assert_eq!(claims.issued_at(&[1; 32]), None);
+        assert_eq!(claims.issued_at(&[2; 32]), Some(6_000));
+    }
+
+    #[test]
+    fn nothing_is_recorded_without_an_age_limit() {
+        let claims = IssuedClaims::new(None);
+        assert_eq!(claims.issue([1; 32], 1_000, 300_000), 1_000);
+        assert_eq!(claims.issued_at(&[1; 32]), None);
+    }
+}
@@ -1,13 +1,54 @@
+extern crate alloc;
+
 use std::collections::HashMap;
 use std::convert::Infallible;
+use std::sync::{Arc, RwLock};
+use warp::http::StatusCode;
 use warp::Filter;
 use serde::{Deserialize, Serialize};
 
+mod config;
+mod degraded;
+mod fee_selection;
+mod gas_monitor;
+mod hashes;
+mod issued_claims;
+mod message;
+mod node;
+mod nonce_cache;
+mod redact;
+mod signers;
+mod token_info;
+mod units;
+mod validation;
+// Shared with the facilitator contract so both build identical messages
+#[path = "../../final-facilitator/src/x402_message.rs"]
+mod x402_message;
+
+use config::{FacilitatorConfig, FeeRates, SharedConfig};
+use degraded::DegradedMode;
+use gas_monitor::{GasMonitorConfig, RelayerStatus};
+use issued_claims::IssuedClaims;
+use node::NodeClient;
+use nonce_cache::NonceCache;
+use signers::SignerPool;
+use token_info::TokenInfoCache;
+
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 struct SignTransactionRequest {
     transaction: String,
     signer_key: Option<String>,
     sig_verify: Option<bool>,
+    /// Gas payment for the relayed deploy; the configured default for
+    /// `entry_point` when omitted
+    gas_payment_motes: Option<u64>,
+    /// Contract entry point the relayed deploy calls, used to pick its
+    /// default gas payment
+    entry_point: Option<String>,
+    /// Account whose permit the relayed deploy claims; its cached nonce is
+    /// dropped once the deploy is submitted
+    payer: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -15,6 +56,9 @@ struct SignTransactionResponse {
     signed_transaction: String,
     signer_pubkey: String,
     signature: String,
+    /// Set when the requested or selected signer timed out and another
+    /// key from the pool signed instead
+    fallback_used: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -31,34 +75,67 @@ struct ConfigResponse {
     supported_tokens: Vec<String>,
     fee_rates: FeeRates,
     endpoints: HashMap<String, String>,
-}
-
-#[derive(Debug, Serialize)]
-struct FeeRates {
-    base_rate: u64,
-    instruction_rate: u64,
-    priority_multiplier: f64,
+    /// Facilitator contract pause state; omitted when the node could not
+    /// be queried
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paused: Option<bool>,
+    /// Reason given by the admin for the current pause
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pause_reason: Option<String>,
+    /// Hex Blake2b-256 of the loaded `FacilitatorConfig`; changes whenever
+    /// the configuration does, so clients can invalidate cached assumptions
+    config_hash: String,
+    /// Set when the node was unreachable and `paused`/`pause_reason` are
+    /// last-known values
+    stale: bool,
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 struct EstimateFeeRequest {
     transaction_size: Option<u64>,
     instruction_count: Option<u32>,
+    /// Number of claims executed by the deploy (e.g. `claim_payment_many`);
+    /// per-claim costs scale with it. Defaults to 1
+    batch_size: Option<u32>,
+    /// A single-claim deploy to cost with the node's speculative execution;
+    /// the rate-based estimate is used when omitted or the node can't run it
+    claim_deploy: Option<serde_json::Value>,
     uses_lookup_tables: Option<bool>,
     is_payment_required: Option<bool>,
+    /// CEP-18 token the fee is quoted in; when omitted, the cheapest
+    /// configured token `payer` can cover, else native CSPR
+    fee_token: Option<String>,
+    /// Paying account, used to pick a fee token when none is given
+    payer: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
 struct EstimateFeeResponse {
     fee_in_lamports: u64,
+    /// Token the fee is quoted in; omitted for native CSPR
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fee_token: Option<String>,
     fee_in_token: Option<u64>,
+    /// `fee_in_token` scaled by the token's decimals, e.g. "1.50 USDC";
+    /// omitted when the token's decimals could not be fetched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fee_in_token_display: Option<String>,
     signer_pubkey: String,
     payment_address: String,
     breakdown: FeeBreakdown,
+    /// Set when the node was unreachable: the display string is a
+    /// last-known value or omitted, and the fee token could not be picked
+    /// from the payer's balances
+    stale: bool,
 }
 
 #[derive(Debug, Serialize)]
 struct FeeBreakdown {
+    batch_size: u32,
+    /// Whether the per-claim cost came from speculative execution rather
+    /// than `instruction_count` and the instruction rate
+    simulated: bool,
     base_fee: u64,
     instruction_fee: u64,
     priority_fee: u64,
@@ -71,6 +148,7 @@ struct SupportedTokensResponse {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 struct PaymentVerificationRequest {
     deploy_hash: String,
     amount: String,
@@ -80,16 +158,124 @@ struct PaymentVerificationRequest {
     signature: Option<String>,
     network: Option<String>,
     timestamp: Option<u64>,
+    /// Sender's permit nonce; checked against the chain when supplied
+    nonce: Option<u64>,
+    /// `deadline` of the signed claim message, in milliseconds; checked
+    /// against node block time when supplied
+    deadline: Option<u64>,
+}
+
+/// Why a payment failed verification, so clients can tell the user whether
+/// to re-sign, adjust the amount, or fix the request
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+enum FailureReason {
+    None,
+    BadSignature,
+    ExpiredDeadline,
+    InvalidNonce,
+    AmountOutOfRange,
+    MalformedInput,
+    StaleSignature,
+    SenderBlocked,
+    /// The payment timestamp is too far from node time
+    ClockSkew,
+    /// The node could not be reached to check the payment
+    NodeUnavailable,
 }
 
 #[derive(Debug, Serialize)]
 struct PaymentVerificationResponse {
     valid: bool,
     message: String,
+    failure_reason: FailureReason,
     transaction_hash: Option<String>,
     timestamp: u64,
 }
 
+/// Request for the canonical claim message a payer must sign
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ClaimMessageRequest {
+    recipient: String,
+    amount: String,
+    /// Filled from the payer's on-chain nonce when omitted
+    nonce: Option<u64>,
+    /// Milliseconds since the Unix epoch; defaults to node block time plus
+    /// `FACILITATOR_DEFAULT_DEADLINE_SECS`
+    deadline: Option<u64>,
+    /// Paying account; required when `nonce` is omitted, and used to check a
+    /// supplied nonce against the chain
+    payer: Option<String>,
+}
+
+/// Canonical claim message; clients must sign `message` byte-for-byte
+#[derive(Debug, Serialize)]
+struct ClaimMessageResponse {
+    message: String,
+    message_hash: String,
+    chain_name: String,
+    contract_hash: String,
+    recipient: String,
+    amount: String,
+    nonce: u64,
+    deadline: u64,
+    /// Node block time (ms) this server first issued the message at; with
+    /// `FACILITATOR_MAX_SIG_AGE_SECS` set, `/verify_payment` measures the
+    /// signature's age from it
+    issued_at: u64,
+    /// Set when a supplied nonce differs from the payer's on-chain nonce
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nonce_warning: Option<String>,
+}
+
+const DEFAULT_DEADLINE_SECS: u64 = 300;
+const DEFAULT_MAX_CLOCK_SKEW_SECS: u64 = 300;
+const DEFAULT_MAX_VERIFY_BATCH: usize = 100;
+
+fn error_reply(status: StatusCode, message: &str) -> warp::reply::WithStatus<warp::reply::Json> {
+    warp::reply::with_status(
+        warp::reply::json(&serde_json::json!({ "error": message })),
+        status,
+    )
+}
+
+/// Reply for a failed node call: 503 when the call queue is saturated, 502
+/// for anything the node itself got wrong
+fn node_error_reply(error: &node::NodeError, message: &str) -> warp::reply::WithStatus<warp::reply::Json> {
+    match error {
+        node::NodeError::Busy => error_reply(StatusCode::SERVICE_UNAVAILABLE, "Node is busy, try again later"),
+        _ => error_reply(StatusCode::BAD_GATEWAY, message),
+    }
+}
+
+fn chain_name() -> String {
+    std::env::var("CASPER_CHAIN_NAME").unwrap_or_else(|_| "casper-custom".to_string())
+}
+
+/// How far past the current block time a default deadline lies, in seconds
+fn default_deadline_secs() -> u64 {
+    std::env::var("FACILITATOR_DEFAULT_DEADLINE_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_DEADLINE_SECS)
+}
+
+/// The requested claim deadline, or `horizon_secs` past `issued_at_ms`
+fn claim_deadline(requested: Option<u64>, issued_at_ms: u64, horizon_secs: u64) -> u64 {
+    requested.unwrap_or_else(|| issued_at_ms.saturating_add(horizon_secs.saturating_mul(1000)))
+}
+
+/// Token contract from `TOKEN_CONTRACT_HASH` (formatted or bare hex)
+fn token_contract_hash() -> Option<hashes::ContractHash> {
+    let hash = std::env::var("TOKEN_CONTRACT_HASH").ok()?;
+    let formatted = if hash.contains('-') {
+        hash
+    } else {
+        format!("{}{}", hashes::HASH_KEY_PREFIX, hash)
+    };
+    hashes::parse_contract_hash(&formatted).ok()
+}
+
 async fn health_handler() -> Result<impl warp::Reply, Infallible> {
     let response = HealthResponse {
         status: "ok".to_string(),
@@ -99,117 +285,817 @@ async fn health_handler() -> Result<impl warp::Reply, Infallible> {
     Ok(warp::reply::json(&response))
 }
 
-async fn config_handler() -> Result<impl warp::Reply, Infallible> {
+async fn config_handler(
+    config: SharedConfig,
+    node: NodeClient,
+    degraded: Arc<DegradedMode>,
+) -> Result<impl warp::Reply, Infallible> {
+    let config = config::snapshot(&config);
+
+    let (paused, pause_reason, stale) = match degraded.pause_state(&node, &config.contract_hash).await {
+        Ok(((paused, reason), stale)) => (Some(paused), reason, stale),
+        Err(e) => {
+            log::warn!("Failed to read pause state of {}: {}", config.contract_hash, e);
+            (None, None, false)
+        }
+    };
+
     let mut endpoints = HashMap::new();
     endpoints.insert("health".to_string(), "/health".to_string());
     endpoints.insert("config".to_string(), "/get_config".to_string());
     endpoints.insert("estimate_fees".to_string(), "/estimate_tx_fees".to_string());
     endpoints.insert("sign_transaction".to_string(), "/sign_tx".to_string());
     endpoints.insert("supported_tokens".to_string(), "/get_supported_tokens".to_string());
+    endpoints.insert("claim_message".to_string(), "/claim_message".to_string());
+    endpoints.insert("token_info".to_string(), "/token_info".to_string());
 
     let response = ConfigResponse {
-        contract_hash: std::env::var("CONTRACT_HASH")
-            .unwrap_or_else(|_| "6a545487ba47c62bdf02f68a9d8ada590fef2a1d28778dd5b346d63927e61b4a".to_string()),
-        network: "casper-custom".to_string(),
-        supported_tokens: vec!["CSPR".to_string()],
-        fee_rates: FeeRates {
-            base_rate: 100000000, // 0.1 CSPR
-            instruction_rate: 10000000, // 0.01 CSPR per instruction
-            priority_multiplier: 1.5,
-        },
+        config_hash: config.hash(),
+        contract_hash: config.contract_hash,
+        network: config.network,
+        supported_tokens: config.supported_tokens,
+        fee_rates: config.fee_rates,
         endpoints,
+        paused,
+        pause_reason,
+        stale,
     };
     Ok(warp::reply::json(&response))
 }
 
-async fn estimate_fees_handler(request: EstimateFeeRequest) -> Result<impl warp::Reply, Infallible> {
+async fn estimate_fees_handler(
+    request: EstimateFeeRequest,
+    node: NodeClient,
+    config: SharedConfig,
+    degraded: Arc<DegradedMode>,
+) -> Result<warp::reply::WithStatus<warp::reply::Json>, Infallible> {
+    let fee_token = match request.fee_token.as_deref().map(hashes::parse_contract_hash) {
+        None => None,
+        Some(Ok(contract_hash)) => Some(contract_hash),
+        Some(Err(e)) => {
+            return Ok(error_reply(StatusCode::BAD_REQUEST, &format!("Invalid fee_token: {}", e)))
+        }
+    };
+    let payer = match request.payer.as_deref().map(hashes::parse_account_hash) {
+        None => None,
+        Some(Ok(payer)) => Some(payer),
+        Some(Err(e)) => {
+            return Ok(error_reply(StatusCode::BAD_REQUEST, &format!("Invalid payer: {}", e)))
+        }
+    };
+
     let transaction_size = request.transaction_size.unwrap_or(250);
     let instruction_count = request.instruction_count.unwrap_or(1);
     
-    let base_fee = 100000000; // 0.1 CSPR
-    let instruction_fee = instruction_count as u64 * 10000000; // 0.01 CSPR per instruction
+    let config = config::snapshot(&config);
+    let batch_size = request.batch_size.unwrap_or(1);
+    if batch_size == 0 || batch_size > config.max_batch_size {
+        return Ok(error_reply(
+            StatusCode::BAD_REQUEST,
+            &format!("batch_size must be between 1 and {}", config.max_batch_size),
+        ));
+    }
+
+    // The base fee is paid once per deploy; instruction costs recur per claim
+    let base_fee = config.fee_rates.base_rate;
+    let (per_claim_fee, simulated) =
+        match fee_selection::speculative_claim_cost(&node, request.claim_deploy.as_ref()).await {
+            Some(cost) => (cost, true),
+            None => (instruction_count as u64 * config.fee_rates.instruction_rate, false),
+        };
+    let instruction_fee = fee_selection::batch_instruction_fee(per_claim_fee, batch_size);
     let priority_fee = (base_fee as f64 * 0.1) as u64; // 10% priority fee
     let total_fee = base_fee + instruction_fee + priority_fee;
 
+    let mut stale = false;
+    let (fee_token, fee_in_token) = match (fee_token, payer) {
+        (Some(contract_hash), _) => {
+            let rate = match fee_selection::token_rate(&config, &contract_hash) {
+                Some(rate) => rate,
+                None => {
+                    return Ok(error_reply(
+                        StatusCode::BAD_REQUEST,
+                        &format!("fee_token {} has no configured rate", contract_hash),
+                    ))
+                }
+            };
+            match fee_selection::fee_in_token(total_fee, rate) {
+                Some(fee_in_token) => (Some(contract_hash), fee_in_token),
+                None => {
+                    return Ok(error_reply(
+                        StatusCode::BAD_REQUEST,
+                        &format!("Fee of {} motes cannot be converted to {}", total_fee, contract_hash),
+                    ))
+                }
+            }
+        }
+        (None, Some(payer)) => {
+            match fee_selection::select_cheapest_fee_token(&node, &config, &payer, total_fee).await {
+                Ok(Some((contract_hash, fee_in_token))) => (Some(contract_hash), fee_in_token),
+                Ok(None) => (None, total_fee),
+                Err(e) if degraded.is_enabled() && e.is_unreachable() => {
+                    log::warn!("Node unreachable; quoting {} in CSPR: {}", payer, e);
+                    stale = true;
+                    (None, total_fee)
+                }
+                Err(e) => {
+                    log::warn!("Failed to select a fee token for {}: {}", payer, e);
+                    return Ok(node_error_reply(&e, "Failed to fetch token balances from node"));
+                }
+            }
+        }
+        (None, None) => (None, total_fee), // Same for CSPR
+    };
+
+    let fee_in_token_display = match fee_token {
+        None => Some(units::format_token_amount(
+            fee_in_token as u128,
+            units::CSPR_DECIMALS,
+            "CSPR",
+        )),
+        Some(contract_hash) => match degraded.token_display_info(&node, &contract_hash.to_hex()).await {
+            Ok(((decimals, symbol), last_known)) => {
+                stale |= last_known;
+                Some(units::format_token_amount(fee_in_token as u128, decimals, &symbol))
+            }
+            Err(node::NodeError::Busy) => {
+                return Ok(node_error_reply(&node::NodeError::Busy, "Failed to fetch token info from node"))
+            }
+            Err(e) => {
+                log::warn!("Failed to fetch display info for token {}: {}", contract_hash, e);
+                stale |= degraded.is_enabled() && e.is_unreachable();
+                None
+            }
+        },
+    };
+
     let response = EstimateFeeResponse {
         fee_in_lamports: total_fee,
-        fee_in_token: Some(total_fee), // Same for CSPR
+        fee_token: fee_token.map(|contract_hash| contract_hash.to_string()),
+        fee_in_token: Some(fee_in_token),
+        fee_in_token_display,
         signer_pubkey: "01234567890abcdef01234567890abcdef01234567890abcdef01234567890abcdef".to_string(),
         payment_address: "account-hash-0123456789abcdef0123456789abcdef01234567".to_string(),
         breakdown: FeeBreakdown {
+            batch_size,
+            simulated,
             base_fee,
             instruction_fee,
             priority_fee,
             total_fee,
         },
+        stale,
     };
-    Ok(warp::reply::json(&response))
+    Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::OK))
 }
 
-async fn sign_transaction_handler(request: SignTransactionRequest) -> Result<impl warp::Reply, Infallible> {
-    // For demo purposes, return a mock signed transaction
+async fn sign_transaction_handler(
+    request: SignTransactionRequest,
+    signer_pool: Arc<SignerPool>,
+) -> Result<warp::reply::WithStatus<warp::reply::Json>, Infallible> {
+    // Only sign with keys from the configured pool
+    let primary = match request.signer_key.as_deref() {
+        Some(signer_key) => match signer_pool.find(signer_key) {
+            Some(signer) => signer,
+            None => {
+                log::warn!("Refused to sign with {}: not in the signer pool", redact::sensitive(signer_key));
+                return Ok(error_reply(StatusCode::FORBIDDEN, "Signer key is not in the signer pool"));
+            }
+        },
+        None => match signer_pool.select() {
+            Some(signer) => signer,
+            None => return Ok(error_reply(StatusCode::SERVICE_UNAVAILABLE, "No signers available")),
+        },
+    };
+
+    // For demo purposes, produce a mock signature
+    let signed = signer_pool
+        .sign_with_fallback(primary, |_signer| async {
+            "mock_signature_".to_string() + &hex::encode([1, 2, 3, 4, 5, 6, 7, 8])
+        })
+        .await;
+    let (signer, signature) = match signed {
+        Some(signed) => signed,
+        None => return Ok(error_reply(StatusCode::GATEWAY_TIMEOUT, "No signer responded in time")),
+    };
+
+    log::info!(
+        "Signed transaction {} with {} (signature {})",
+        redact::sensitive(&request.transaction),
+        redact::sensitive(&signer.public_key),
+        redact::sensitive(&signature)
+    );
+
     let response = SignTransactionResponse {
         signed_transaction: format!("signed_{}", request.transaction),
-        signer_pubkey: "01234567890abcdef01234567890abcdef01234567890abcdef01234567890abcdef".to_string(),
-        signature: "mock_signature_".to_string() + &hex::encode(&[1, 2, 3, 4, 5, 6, 7, 8]),
+        signer_pubkey: signer.public_key.clone(),
+        signature,
+        fallback_used: signer.public_key != primary.public_key,
     };
-    Ok(warp::reply::json(&response))
+    Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::OK))
 }
 
-async fn send_transaction_handler(_request: SignTransactionRequest) -> Result<impl warp::Reply, Infallible> {
+async fn send_transaction_handler(
+    request: SignTransactionRequest,
+    relayer_status: Arc<RelayerStatus>,
+    config: SharedConfig,
+    node: NodeClient,
+    degraded: Arc<DegradedMode>,
+    nonce_cache: Arc<NonceCache>,
+) -> Result<impl warp::Reply, Infallible> {
+    if relayer_status.is_relaying_paused() {
+        let response = serde_json::json!({
+            "error": "Relayer gas balance is below the configured threshold",
+            "status": "unavailable"
+        });
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            StatusCode::SERVICE_UNAVAILABLE,
+        ));
+    }
+
+    if let Err(e) = degraded.check_node_for_write(&node).await {
+        log::warn!("Refusing to relay while the node is unreachable: {}", e);
+        return Ok(error_reply(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Node is unreachable, try again later",
+        ));
+    }
+
+    let payer = match request.payer.as_deref().map(hashes::parse_account_hash) {
+        Some(Ok(payer)) => Some(payer),
+        Some(Err(e)) => {
+            return Ok(error_reply(StatusCode::BAD_REQUEST, &format!("Invalid payer: {}", e)))
+        }
+        None => None,
+    };
+
+    let config = config::snapshot(&config);
+    if config.sender_blocked(payer.as_ref()) {
+        let payer = payer.map_or_else(|| "unknown payer".to_string(), |p| p.to_string());
+        let response = serde_json::json!({
+            "error": format!("Payments from {} are not accepted by this facilitator", payer),
+            "status": "sender_blocked"
+        });
+        return Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::FORBIDDEN));
+    }
+
+    let gas_payment_motes = request
+        .gas_payment_motes
+        .unwrap_or_else(|| config.default_gas_payment_for(request.entry_point.as_deref()));
+    if gas_payment_motes < config.min_gas_payment_motes {
+        return Ok(error_reply(
+            StatusCode::BAD_REQUEST,
+            &format!(
+                "gas_payment_motes {} is below the minimum of {} motes",
+                gas_payment_motes, config.min_gas_payment_motes
+            ),
+        ));
+    }
+
+    log::info!(
+        "Relaying transaction {} with {} motes of gas",
+        redact::sensitive(&request.transaction),
+        gas_payment_motes
+    );
+
+    // The claim consumes the payer's nonce, so the cached value is stale
+    // once it executes
+    if let (Some(payer), Some(contract_hash)) = (payer, token_contract_hash()) {
+        nonce_cache.claim_submitted(&contract_hash.to_hex(), &payer.to_hex());
+    }
+
     // For demo purposes, return a mock transaction hash
     let response = serde_json::json!({
-        "transaction_hash": format!("tx_hash_{}", hex::encode(&[9, 10, 11, 12, 13, 14, 15, 16])),
+        "transaction_hash": format!("tx_hash_{}", hex::encode([9, 10, 11, 12, 13, 14, 15, 16])),
+        "gas_payment_motes": gas_payment_motes,
         "status": "submitted"
     });
-    Ok(warp::reply::json(&response))
+    Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::OK))
 }
 
-async fn supported_tokens_handler() -> Result<impl warp::Reply, Infallible> {
+async fn supported_tokens_handler(config: SharedConfig) -> Result<impl warp::Reply, Infallible> {
     let response = SupportedTokensResponse {
-        tokens: vec!["CSPR".to_string()],
+        tokens: config::snapshot(&config).supported_tokens,
     };
     Ok(warp::reply::json(&response))
 }
 
-async fn verify_payment_handler(request: PaymentVerificationRequest) -> Result<impl warp::Reply, Infallible> {
-    let sender_ok = request
-        .sender
-        .as_deref()
-        .map(|s| !s.is_empty())
-        .unwrap_or(false);
-    let public_key_ok = request
-        .public_key
-        .as_deref()
-        .map(|s| !s.is_empty())
-        .unwrap_or(false);
-    let signature_ok = request
-        .signature
-        .as_deref()
-        .map(|s| !s.is_empty())
-        .unwrap_or(false);
-
-    let valid = !request.deploy_hash.is_empty()
-        && (sender_ok || (public_key_ok && signature_ok))
-        && !request.amount.is_empty();
+/// Maximum allowed distance between a client timestamp and node time
+fn max_clock_skew_secs() -> u64 {
+    std::env::var("FACILITATOR_MAX_CLOCK_SKEW_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_MAX_CLOCK_SKEW_SECS)
+}
+
+/// Node reads shared by the payments of one verification request, so a
+/// batch fetches the block time and each sender's nonce only once
+struct NodeReads<'a> {
+    node: &'a NodeClient,
+    nonce_cache: &'a NonceCache,
+    block_time_ms: Option<u64>,
+    nonces: HashMap<(String, String), u64>,
+}
+
+impl<'a> NodeReads<'a> {
+    fn new(node: &'a NodeClient, nonce_cache: &'a NonceCache) -> Self {
+        Self {
+            node,
+            nonce_cache,
+            block_time_ms: None,
+            nonces: HashMap::new(),
+        }
+    }
+
+    async fn block_time_ms(&mut self) -> Result<u64, node::NodeError> {
+        if let Some(block_time_ms) = self.block_time_ms {
+            return Ok(block_time_ms);
+        }
+        let block_time_ms = self.node.get_latest_block_time_ms().await?;
+        self.block_time_ms = Some(block_time_ms);
+        Ok(block_time_ms)
+    }
+
+    async fn account_nonce(&mut self, token_contract_hash: &str, account_hash: &str) -> Result<u64, node::NodeError> {
+        let key = (token_contract_hash.to_string(), account_hash.to_string());
+        if let Some(nonce) = self.nonces.get(&key) {
+            return Ok(*nonce);
+        }
+        let nonce = self.nonce_cache.get(self.node, token_contract_hash, account_hash).await?;
+        self.nonces.insert(key, nonce);
+        Ok(nonce)
+    }
+}
+
+/// Reject timestamps (Unix seconds) further than the allowed skew from the
+/// latest block time, in either direction.
+///
+/// Returns the rejection, if any; only a saturated node call queue is
+/// reported as an error so the caller can answer 503.
+async fn check_clock_skew(
+    reads: &mut NodeReads<'_>,
+    timestamp: u64,
+) -> Result<Option<(FailureReason, String)>, node::NodeError> {
+    let node_time_secs = match reads.block_time_ms().await {
+        Ok(ms) => ms / 1000,
+        Err(node::NodeError::Busy) => return Err(node::NodeError::Busy),
+        Err(e) => {
+            log::warn!("Failed to fetch block time for skew check: {}", e);
+            return Ok(Some((
+                FailureReason::NodeUnavailable,
+                "Unable to check timestamp against node time".to_string(),
+            )));
+        }
+    };
+
+    let max_skew = max_clock_skew_secs();
+    if timestamp.abs_diff(node_time_secs) > max_skew {
+        return Ok(Some((
+            FailureReason::ClockSkew,
+            format!("Timestamp {} is more than {}s from node time {}", timestamp, max_skew, node_time_secs),
+        )));
+    }
+    Ok(None)
+}
+
+/// Why `signature` by `public_key` does not authorize the payment, if it
+/// does not.
+///
+/// The claim message the payer signed is rebuilt from the payment, so the
+/// signature only checks out for this chain, token contract, recipient,
+/// amount, nonce and deadline.
+fn signature_failure(
+    request: &PaymentVerificationRequest,
+    public_key: &str,
+    signature: &str,
+    amount: u128,
+    chain_name: &str,
+    contract_hash: &hashes::ContractHash,
+) -> Option<(FailureReason, String)> {
+    let message = match signed_claim_message(request, amount, chain_name, contract_hash) {
+        Ok(message) => message,
+        Err(failure) => return Some(failure),
+    };
+    if message::verify_claim_signature(&message, public_key, signature) {
+        return None;
+    }
+    Some((
+        FailureReason::BadSignature,
+        "Signature is not the public key's signature over this payment's claim message".to_string(),
+    ))
+}
+
+/// The claim message the payer signed for this payment, rebuilt from the
+/// payment's fields
+fn signed_claim_message(
+    request: &PaymentVerificationRequest,
+    amount: u128,
+    chain_name: &str,
+    contract_hash: &hashes::ContractHash,
+) -> Result<String, (FailureReason, String)> {
+    let (Some(nonce), Some(deadline)) = (request.nonce, request.deadline) else {
+        return Err((
+            FailureReason::MalformedInput,
+            "nonce and deadline of the signed claim message are required".to_string(),
+        ));
+    };
+    let recipient = hashes::parse_account_hash(&request.recipient)
+        .map_err(|e| (FailureReason::MalformedInput, format!("Invalid recipient: {}", e)))?;
+    Ok(message::build_claim_message(
+        chain_name,
+        &contract_hash.to_hex(),
+        &recipient.to_hex(),
+        &amount.to_string(),
+        nonce,
+        deadline,
+    ))
+}
+
+/// Why a payment could not be verified at all
+enum VerifyError {
+    /// The request itself is unusable (400)
+    BadRequest(String),
+    /// A node call failed; answered with [`node_error_reply`]
+    Node(node::NodeError, &'static str),
+    /// The server lacks configuration the check needs (500)
+    Unconfigured(&'static str),
+}
+
+async fn verify_payment(
+    request: PaymentVerificationRequest,
+    reads: &mut NodeReads<'_>,
+    issued_claims: &IssuedClaims,
+    config: &FacilitatorConfig,
+) -> Result<PaymentVerificationResponse, VerifyError> {
+    if let Err(e) = hashes::parse_account_hash(&request.recipient) {
+        return Err(VerifyError::BadRequest(format!("Invalid recipient: {}", e)));
+    }
+    let sender = match request.sender.as_deref().filter(|s| !s.is_empty()) {
+        Some(sender) => match hashes::parse_account_hash(sender) {
+            Ok(sender) => Some(sender),
+            Err(e) => return Err(VerifyError::BadRequest(format!("Invalid sender: {}", e))),
+        },
+        None => None,
+    };
+
+    let public_key = request.public_key.as_deref().filter(|s| !s.is_empty());
+    let signature = request.signature.as_deref().filter(|s| !s.is_empty());
+    let amount = request.amount.trim().parse::<u128>();
+
+    let failure = if request.deploy_hash.is_empty()
+        || (sender.is_none() && (public_key.is_none() || signature.is_none()))
+    {
+        Some((FailureReason::MalformedInput, "Invalid payment data".to_string()))
+    } else if amount.is_err() {
+        Some((FailureReason::MalformedInput, format!("Invalid amount: {}", request.amount)))
+    } else if amount == Ok(0) {
+        Some((FailureReason::AmountOutOfRange, "Amount must be greater than zero".to_string()))
+    } else if let (Some(public_key), Some(signature), Ok(amount)) = (public_key, signature, &amount) {
+        let contract_hash = token_contract_hash()
+            .ok_or(VerifyError::Unconfigured("TOKEN_CONTRACT_HASH is not configured"))?;
+        signature_failure(&request, public_key, signature, *amount, &chain_name(), &contract_hash)
+    } else {
+        None
+    };
+
+    // Screen the sender, or the account the signing key belongs to
+    let failure = failure.or_else(|| {
+        let account = sender.or_else(|| public_key.and_then(hashes::account_hash_from_public_key));
+        config.sender_blocked(account.as_ref()).then(|| {
+            let account = account.map_or_else(|| "unknown sender".to_string(), |a| a.to_string());
+            (
+                FailureReason::SenderBlocked,
+                format!("Payments from {} are not accepted by this facilitator", account),
+            )
+        })
+    });
+
+    // A supplied nonce must match the sender's on-chain permit nonce
+    let failure = match (failure, request.nonce, sender, token_contract_hash()) {
+        (None, Some(nonce), Some(sender), Some(contract_hash)) => {
+            match reads.account_nonce(&contract_hash.to_hex(), &sender.to_hex()).await {
+                Ok(on_chain) if on_chain != nonce => Some((
+                    FailureReason::InvalidNonce,
+                    format!("Nonce {} does not match on-chain nonce {}", nonce, on_chain),
+                )),
+                Ok(_) => None,
+                Err(e) => {
+                    log::warn!("Failed to fetch nonce for {}: {}", sender, e);
+                    return Err(VerifyError::Node(e, "Failed to fetch nonce from node"));
+                }
+            }
+        }
+        (failure, ..) => failure,
+    };
+
+    let failure = match (failure, request.timestamp) {
+        (None, Some(timestamp)) => match check_clock_skew(reads, timestamp).await {
+            Ok(failure) => failure,
+            Err(e) => return Err(VerifyError::Node(e, "Failed to fetch block time from node")),
+        },
+        (failure, _) => failure,
+    };
+
+    // Same block-time basis as the contract's `validate_deadline`
+    let failure = match (failure, request.deadline) {
+        (None, Some(deadline)) => match reads.block_time_ms().await {
+            Ok(now_ms) if now_ms > deadline => Some((
+                FailureReason::ExpiredDeadline,
+                format!("Deadline {} has passed; node time is {}", deadline, now_ms),
+            )),
+            Ok(_) => None,
+            Err(node::NodeError::Busy) => {
+                return Err(VerifyError::Node(node::NodeError::Busy, "Failed to fetch block time from node"))
+            }
+            Err(e) => {
+                log::warn!("Failed to fetch block time for deadline check: {}", e);
+                Some((
+                    FailureReason::NodeUnavailable,
+                    "Unable to check deadline against node time".to_string(),
+                ))
+            }
+        },
+        (failure, _) => failure,
+    };
+
+    // Signature age runs from when this server issued the signed message,
+    // not from any time the client reports
+    let failure = match (failure, issued_claims.max_age_secs(), &amount) {
+        (None, Some(max_age_secs), Ok(amount)) => {
+            let contract_hash = token_contract_hash()
+                .ok_or(VerifyError::Unconfigured("TOKEN_CONTRACT_HASH is not configured"))?;
+            match signed_claim_message(&request, *amount, &chain_name(), &contract_hash) {
+                Err(failure) => Some(failure),
+                Ok(message) => match issued_claims.issued_at(&message::message_hash(&message)) {
+                    None => Some((
+                        FailureReason::StaleSignature,
+                        "Claim message was not issued by this facilitator or has expired; request a new claim message and re-sign".to_string(),
+                    )),
+                    Some(issued_at) => match reads.block_time_ms().await {
+                        Ok(now_ms) => message::signature_age_failure(issued_at, now_ms, max_age_secs)
+                            .map(|reason| (FailureReason::StaleSignature, reason)),
+                        Err(e) => {
+                            log::warn!("Failed to fetch block time for signature age check: {}", e);
+                            return Err(VerifyError::Node(e, "Failed to fetch block time from node"));
+                        }
+                    },
+                },
+            }
+        }
+        (failure, ..) => failure,
+    };
 
     let now = request.timestamp.unwrap_or_else(|| chrono::Utc::now().timestamp() as u64);
 
-    let response = PaymentVerificationResponse {
-        valid,
-        message: if valid { 
-            "Payment verified successfully".to_string() 
-        } else { 
-            "Invalid payment data".to_string() 
+    Ok(match failure {
+        None => PaymentVerificationResponse {
+            valid: true,
+            message: "Payment verified successfully".to_string(),
+            failure_reason: FailureReason::None,
+            transaction_hash: Some(request.deploy_hash.clone()),
+            timestamp: now,
         },
-        transaction_hash: if valid { 
-            Some(request.deploy_hash.clone()) 
-        } else { 
-            None 
+        Some((failure_reason, message)) => PaymentVerificationResponse {
+            valid: false,
+            message,
+            failure_reason,
+            transaction_hash: None,
+            timestamp: now,
         },
-        timestamp: now,
+    })
+}
+
+async fn verify_payment_handler(
+    request: PaymentVerificationRequest,
+    node: NodeClient,
+    config: SharedConfig,
+    nonce_cache: Arc<NonceCache>,
+    issued_claims: Arc<IssuedClaims>,
+) -> Result<warp::reply::WithStatus<warp::reply::Json>, Infallible> {
+    let config = config::snapshot(&config);
+    match verify_payment(request, &mut NodeReads::new(&node, &nonce_cache), &issued_claims, &config).await {
+        Ok(response) => Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::OK)),
+        Err(VerifyError::BadRequest(message)) => Ok(error_reply(StatusCode::BAD_REQUEST, &message)),
+        Err(VerifyError::Node(e, message)) => Ok(node_error_reply(&e, message)),
+        Err(VerifyError::Unconfigured(message)) => Ok(error_reply(StatusCode::INTERNAL_SERVER_ERROR, message)),
+    }
+}
+
+/// Largest number of payments one `/verify_payment/batch` call may verify
+fn max_verify_batch_size() -> usize {
+    std::env::var("FACILITATOR_MAX_VERIFY_BATCH")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|n| *n > 0)
+        .unwrap_or(DEFAULT_MAX_VERIFY_BATCH)
+}
+
+/// Verify payments in order, sharing node reads across the batch.
+///
+/// An entry that is unusable on its own fails with `MalformedInput`
+/// instead of failing the whole batch; a node failure fails the batch.
+async fn verify_payment_batch(
+    requests: Vec<PaymentVerificationRequest>,
+    node: &NodeClient,
+    nonce_cache: &NonceCache,
+    issued_claims: &IssuedClaims,
+    config: &FacilitatorConfig,
+) -> Result<Vec<PaymentVerificationResponse>, VerifyError> {
+    let mut reads = NodeReads::new(node, nonce_cache);
+    let mut responses = Vec::with_capacity(requests.len());
+    for request in requests {
+        let timestamp = request.timestamp.unwrap_or_else(|| chrono::Utc::now().timestamp() as u64);
+        let response = match verify_payment(request, &mut reads, issued_claims, config).await {
+            Ok(response) => response,
+            Err(VerifyError::BadRequest(message)) => PaymentVerificationResponse {
+                valid: false,
+                message,
+                failure_reason: FailureReason::MalformedInput,
+                transaction_hash: None,
+                timestamp,
+            },
+            Err(e) => return Err(e),
+        };
+        responses.push(response);
+    }
+    Ok(responses)
+}
+
+async fn verify_payment_batch_handler(
+    requests: Vec<PaymentVerificationRequest>,
+    node: NodeClient,
+    config: SharedConfig,
+    nonce_cache: Arc<NonceCache>,
+    issued_claims: Arc<IssuedClaims>,
+) -> Result<warp::reply::WithStatus<warp::reply::Json>, Infallible> {
+    let max_batch = max_verify_batch_size();
+    if requests.is_empty() || requests.len() > max_batch {
+        return Ok(error_reply(
+            StatusCode::BAD_REQUEST,
+            &format!("Batch must contain between 1 and {} payments", max_batch),
+        ));
+    }
+
+    match verify_payment_batch(requests, &node, &nonce_cache, &issued_claims, &config::snapshot(&config)).await {
+        Ok(responses) => Ok(warp::reply::with_status(warp::reply::json(&responses), StatusCode::OK)),
+        Err(VerifyError::BadRequest(message)) => Ok(error_reply(StatusCode::BAD_REQUEST, &message)),
+        Err(VerifyError::Node(e, message)) => Ok(node_error_reply(&e, message)),
+        Err(VerifyError::Unconfigured(message)) => Ok(error_reply(StatusCode::INTERNAL_SERVER_ERROR, message)),
+    }
+}
+
+
+async fn token_info_handler(
+    node: NodeClient,
+    cache: Arc<TokenInfoCache>,
+) -> Result<warp::reply::WithStatus<warp::reply::Json>, Infallible> {
+    let contract_hash = match token_contract_hash() {
+        Some(hash) => hash,
+        None => {
+            return Ok(error_reply(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "TOKEN_CONTRACT_HASH is not configured",
+            ))
+        }
     };
-    Ok(warp::reply::json(&response))
+
+    match cache.get(&node, &contract_hash.to_hex()).await {
+        Ok(info) => Ok(warp::reply::with_status(warp::reply::json(&info), StatusCode::OK)),
+        Err(e) => {
+            log::warn!("Failed to read token metadata for {}: {}", contract_hash, e);
+            Ok(node_error_reply(
+                &e,
+                &format!("Failed to read token metadata from {}; check TOKEN_CONTRACT_HASH", contract_hash),
+            ))
+        }
+    }
+}
+
+/// Warning returned with a claim message whose supplied nonce differs from
+/// the payer's on-chain nonce
+fn nonce_mismatch_warning(supplied: u64, on_chain: u64) -> Option<String> {
+    if supplied == on_chain {
+        return None;
+    }
+    Some(format!(
+        "Supplied nonce {} does not match on-chain nonce {}; the claim will fail validate_nonce",
+        supplied, on_chain
+    ))
+}
+
+async fn claim_message_handler(
+    request: ClaimMessageRequest,
+    node: NodeClient,
+    config: SharedConfig,
+    nonce_cache: Arc<NonceCache>,
+    issued_claims: Arc<IssuedClaims>,
+) -> Result<warp::reply::WithStatus<warp::reply::Json>, Infallible> {
+    let contract_hash = match token_contract_hash() {
+        Some(hash) => hash,
+        None => {
+            return Ok(error_reply(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "TOKEN_CONTRACT_HASH is not configured",
+            ))
+        }
+    };
+
+    let recipient = match hashes::parse_account_hash(&request.recipient) {
+        Ok(recipient) => recipient,
+        Err(e) => return Ok(error_reply(StatusCode::BAD_REQUEST, &format!("Invalid recipient: {}", e))),
+    };
+
+    let amount = match units::parse_u256_amount(&request.amount) {
+        Ok(amount) => amount,
+        Err(e) => return Ok(error_reply(StatusCode::BAD_REQUEST, &format!("Invalid amount: {}", e))),
+    };
+    if let Some(reason) = config::snapshot(&config).amount_out_of_range(&contract_hash, &amount) {
+        return Ok(error_reply(StatusCode::BAD_REQUEST, &format!("Invalid amount: {}", reason)));
+    }
+
+    let payer = match request.payer.as_deref().map(hashes::parse_account_hash) {
+        Some(Ok(payer)) => Some(payer),
+        Some(Err(e)) => {
+            return Ok(error_reply(StatusCode::BAD_REQUEST, &format!("Invalid payer: {}", e)))
+        }
+        None => None,
+    };
+
+    // Omitted nonces are filled from the token's `nonces` dictionary; supplied
+    // ones are checked against it when the payer is known
+    let mut nonce_warning = None;
+    let nonce = match (request.nonce, payer) {
+        (None, None) => {
+            return Ok(error_reply(
+                StatusCode::BAD_REQUEST,
+                "A payer account hash is required when nonce is omitted",
+            ))
+        }
+        (None, Some(payer)) => {
+            match nonce_cache.get(&node, &contract_hash.to_hex(), &payer.to_hex()).await {
+                Ok(nonce) => nonce,
+                Err(e) => {
+                    log::warn!("Failed to fetch nonce for {}: {}", payer, e);
+                    return Ok(node_error_reply(&e, "Failed to fetch nonce from node"));
+                }
+            }
+        }
+        (Some(nonce), Some(payer)) => {
+            match nonce_cache.get(&node, &contract_hash.to_hex(), &payer.to_hex()).await {
+                Ok(on_chain) => {
+                    nonce_warning = nonce_mismatch_warning(nonce, on_chain);
+                    if nonce_warning.is_some() {
+                        log::warn!("Supplied nonce {} for {} does not match on-chain nonce {}", nonce, payer, on_chain);
+                    }
+                }
+                Err(node::NodeError::Busy) => {
+                    return Ok(node_error_reply(&node::NodeError::Busy, "Failed to check nonce against node"))
+                }
+                Err(e) => log::warn!("Failed to check nonce for {}: {}", payer, e),
+            }
+            nonce
+        }
+        (Some(nonce), None) => nonce,
+    };
+
+    // Node block time rather than the server's clock, so default deadlines
+    // agree with `validate_deadline` on-chain
+    let now_ms = match node.get_latest_block_time_ms().await {
+        Ok(block_time_ms) => block_time_ms,
+        Err(e) => {
+            log::warn!("Failed to fetch block time: {}", e);
+            return Ok(node_error_reply(&e, "Failed to fetch block time from node"));
+        }
+    };
+    let deadline = claim_deadline(request.deadline, now_ms, default_deadline_secs());
+
+    let chain_name = chain_name();
+    let message = message::build_claim_message(
+        &chain_name,
+        &contract_hash.to_hex(),
+        &recipient.to_hex(),
+        &amount,
+        nonce,
+        deadline,
+    );
+
+    let message_hash = message::message_hash(&message);
+    let issued_at = issued_claims.issue(message_hash, now_ms, deadline);
+
+    let response = ClaimMessageResponse {
+        message_hash: hex::encode(message_hash),
+        message,
+        chain_name,
+        contract_hash: contract_hash.to_hex(),
+        recipient: recipient.to_hex(),
+        amount,
+        nonce,
+        deadline,
+        issued_at,
+        nonce_warning,
+    };
+    Ok(warp::reply::with_status(warp::reply::json(&response), StatusCode::OK))
 }
 
 #[tokio::main]
@@ -227,6 +1113,51 @@ async fn main() {
 
     println!("🚀 Starting Casper Facilitator Server on port {}", port);
 
+    let facilitator_config = match FacilitatorConfig::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+    println!("⚙️  Config hash: {}", facilitator_config.hash());
+    let facilitator_config: SharedConfig = Arc::new(RwLock::new(facilitator_config));
+    config::spawn_reload_on_sighup(facilitator_config.clone());
+
+    let node = NodeClient::from_env();
+    let signer_pool = Arc::new(SignerPool::from_env());
+    println!("🔑 Signer pool: {} key(s)", signer_pool.len());
+    println!(
+        "🔗 Casper node RPC: {} (max {} concurrent calls)",
+        node.rpc_url(),
+        node.max_concurrent_calls()
+    );
+    let relayer_status = Arc::new(RelayerStatus::default());
+
+    // Relayer gas balance monitoring
+    if let Some(monitor_config) = GasMonitorConfig::from_env() {
+        println!(
+            "⛽ Monitoring relayer {} (threshold: {} motes)",
+            monitor_config.relayer_account, monitor_config.min_balance_motes
+        );
+        gas_monitor::spawn_gas_monitor(node.clone(), monitor_config, relayer_status.clone());
+    }
+    let degraded = Arc::new(DegradedMode::from_env());
+    if degraded.is_enabled() {
+        println!("🩹 Degraded mode: serving last-known node data while the node is unreachable");
+    }
+    let degraded = warp::any().map(move || degraded.clone());
+    let relayer_status = warp::any().map(move || relayer_status.clone());
+    let node = warp::any().map(move || node.clone());
+    let signer_pool = warp::any().map(move || signer_pool.clone());
+    let token_info_cache = Arc::new(TokenInfoCache::from_env());
+    let token_info_cache = warp::any().map(move || token_info_cache.clone());
+    let nonce_cache = Arc::new(NonceCache::from_env());
+    let nonce_cache = warp::any().map(move || nonce_cache.clone());
+    let issued_claims = Arc::new(IssuedClaims::from_env());
+    let issued_claims = warp::any().map(move || issued_claims.clone());
+    let facilitator_config = warp::any().map(move || facilitator_config.clone());
+
     // CORS configuration
     let cors = warp::cors()
         .allow_any_origin()
@@ -241,37 +1172,82 @@ async fn main() {
     // Config endpoint
     let config = warp::path("get_config")
         .and(warp::get())
+        .and(facilitator_config.clone())
+        .and(node.clone())
+        .and(degraded.clone())
         .and_then(config_handler);
 
     // Estimate fees endpoint
     let estimate_fees = warp::path("estimate_tx_fees")
         .and(warp::post())
-        .and(warp::body::json())
+        .and(validation::json_body())
+        .and(node.clone())
+        .and(facilitator_config.clone())
+        .and(degraded.clone())
         .and_then(estimate_fees_handler);
 
     // Sign transaction endpoint
     let sign_tx = warp::path("sign_tx")
         .and(warp::post())
-        .and(warp::body::json())
+        .and(validation::json_body())
+        .and(signer_pool.clone())
         .and_then(sign_transaction_handler);
 
     // Send transaction endpoint
     let send_tx = warp::path("send_tx")
         .and(warp::post())
-        .and(warp::body::json())
+        .and(validation::json_body())
+        .and(relayer_status.clone())
+        .and(facilitator_config.clone())
+        .and(node.clone())
+        .and(degraded.clone())
+        .and(nonce_cache.clone())
         .and_then(send_transaction_handler);
 
     // Supported tokens endpoint
     let supported_tokens = warp::path("get_supported_tokens")
         .and(warp::get())
+        .and(facilitator_config.clone())
         .and_then(supported_tokens_handler);
 
     // Payment verification endpoint
     let verify_payment = warp::path("verify_payment")
+        .and(warp::path::end())
         .and(warp::post())
-        .and(warp::body::json())
+        .and(validation::json_body())
+        .and(node.clone())
+        .and(facilitator_config.clone())
+        .and(nonce_cache.clone())
+        .and(issued_claims.clone())
         .and_then(verify_payment_handler);
 
+    // Batch payment verification endpoint
+    let verify_payment_batch = warp::path!("verify_payment" / "batch")
+        .and(warp::post())
+        .and(validation::json_body())
+        .and(node.clone())
+        .and(facilitator_config.clone())
+        .and(nonce_cache.clone())
+        .and(issued_claims.clone())
+        .and_then(verify_payment_batch_handler);
+
+    // Claim message endpoint
+    let claim_message = warp::path("claim_message")
+        .and(warp::post())
+        .and(validation::json_body())
+        .and(node.clone())
+        .and(facilitator_config.clone())
+        .and(nonce_cache)
+        .and(issued_claims)
+        .and_then(claim_message_handler);
+
+    // Token metadata endpoint
+    let token_info = warp::path("token_info")
+        .and(warp::get())
+        .and(node.clone())
+        .and(token_info_cache)
+        .and_then(token_info_handler);
+
     // Combine all routes
     let routes = health
         .or(config)
@@ -280,6 +1256,10 @@ async fn main() {
         .or(send_tx)
         .or(supported_tokens)
         .or(verify_payment)
+        .or(verify_payment_batch)
+        .or(claim_message)
+        .or(token_info)
+        .recover(validation::recover_invalid_body)
         .with(cors);
 
     println!("📡 Facilitator endpoints:");
@@ -289,8 +1269,630 @@ async fn main() {
     println!("   • Sign Transaction: http://localhost:{}/sign_tx", port);
     println!("   • Supported Tokens: http://localhost:{}/get_supported_tokens", port);
     println!("   • Verify Payment: http://localhost:{}/verify_payment", port);
+    println!("   • Verify Payment Batch: http://localhost:{}/verify_payment/batch", port);
+    println!("   • Claim Message: http://localhost:{}/claim_message", port);
+    println!("   • Token Info: http://localhost:{}/token_info", port);
 
     warp::serve(routes)
         .run(([127, 0, 0, 1], port))
         .await;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECIPIENT: &str = "account-hash-0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+    const SENDER: &str = "account-hash-fedcba9876543210fedcba9876543210fedcba9876543210fedcba9876543210";
+
+    fn payment(deploy_hash: &str, recipient: &str, amount: &str) -> PaymentVerificationRequest {
+        PaymentVerificationRequest {
+            deploy_hash: deploy_hash.to_string(),
+            amount: amount.to_string(),
+            recipient: recipient.to_string(),
+            sender: Some(SENDER.to_string()),
+            public_key: None,
+            signature: None,
+            network: None,
+            timestamp: None,
+            nonce: None,
+            deadline: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_reports_each_payment_in_order() {
+        // None of these payments needs the node
+        let node = node::unreachable_node();
+        let requests = vec![
+            payment("deploy-1", RECIPIENT, "1000"),
+            payment("deploy-2", RECIPIENT, "0"),
+            payment("deploy-3", "not-an-account", "1000"),
+            payment("deploy-4", RECIPIENT, "abc"),
+            payment("deploy-5", RECIPIENT, "25"),
+        ];
+
+        let nonce_cache = NonceCache::new(std::time::Duration::ZERO);
+        let responses = match verify_payment_batch(requests, &node, &nonce_cache, &IssuedClaims::new(None), &FacilitatorConfig::default()).await {
+            Ok(responses) => responses,
+            Err(_) => panic!("batch verification failed"),
+        };
+
+        let outcomes: Vec<(bool, FailureReason)> =
+            responses.iter().map(|r| (r.valid, r.failure_reason)).collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                (true, FailureReason::None),
+                (false, FailureReason::AmountOutOfRange),
+                (false, FailureReason::MalformedInput),
+                (false, FailureReason::MalformedInput),
+                (true, FailureReason::None),
+            ]
+        );
+        assert_eq!(responses[0].transaction_hash.as_deref(), Some("deploy-1"));
+        assert_eq!(responses[4].transaction_hash.as_deref(), Some("deploy-5"));
+        assert!(responses[2].message.starts_with("Invalid recipient"));
+    }
+
+    #[tokio::test]
+    async fn blocked_senders_fail_verification() {
+        let node = node::unreachable_node();
+        let config = FacilitatorConfig {
+            sender_denylist: vec![SENDER.to_string()],
+            ..FacilitatorConfig::default()
+        };
+
+        let mut other_sender = payment("deploy-2", RECIPIENT, "1000");
+        other_sender.sender = Some(RECIPIENT.to_string());
+        let requests = vec![payment("deploy-1", RECIPIENT, "1000"), other_sender];
+
+        let nonce_cache = NonceCache::new(std::time::Duration::ZERO);
+        let responses = match verify_payment_batch(requests, &node, &nonce_cache, &IssuedClaims::new(None), &config).await {
+            Ok(responses) => responses,
+            Err(_) => panic!("batch verification failed"),
+        };
+        assert!(!responses[0].valid);
+        assert_eq!(responses[0].failure_reason, FailureReason::SenderBlocked);
+        assert!(responses[1].valid);
+    }
+
+    fn relay_request(payer: &str, gas_payment_motes: Option<u64>, entry_point: Option<&str>) -> SignTransactionRequest {
+        SignTransactionRequest {
+            transaction: "deploy".to_string(),
+            signer_key: None,
+            sig_verify: None,
+            gas_payment_motes,
+            entry_point: entry_point.map(str::to_string),
+            payer: Some(payer.to_string()),
+        }
+    }
+
+    /// Status and JSON body of a `/send_tx` call against an unreachable node
+    async fn send_tx(request: SignTransactionRequest, config: FacilitatorConfig) -> (StatusCode, serde_json::Value) {
+        let reply = match send_transaction_handler(
+            request,
+            Arc::new(RelayerStatus::default()),
+            Arc::new(RwLock::new(config)),
+            node::unreachable_node(),
+            Arc::new(DegradedMode::new(false)),
+            Arc::new(NonceCache::new(std::time::Duration::ZERO)),
+        )
+        .await
+        {
+            Ok(reply) => reply,
+            Err(never) => match never {},
+        };
+        let response = warp::Reply::into_response(reply);
+        let status = response.status();
+        let body = warp::hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn blocked_payers_are_not_relayed() {
+        let config = FacilitatorConfig {
+            sender_denylist: vec![SENDER.to_string()],
+            ..FacilitatorConfig::default()
+        };
+
+        let (status, body) = send_tx(relay_request(SENDER, None, None), config.clone()).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["status"], "sender_blocked");
+
+        let (status, _) = send_tx(relay_request(RECIPIENT, None, None), config).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn relayed_deploys_use_the_entry_point_gas_default() {
+        let config = FacilitatorConfig {
+            entry_point_gas_payment_motes: std::collections::BTreeMap::from([("claim_batch".to_string(), 10_000_000_000)]),
+            ..FacilitatorConfig::default()
+        };
+
+        let (_, body) = send_tx(relay_request(SENDER, None, Some("claim_batch")), config.clone()).await;
+        assert_eq!(body["gas_payment_motes"], 10_000_000_000u64);
+        let (_, body) = send_tx(relay_request(SENDER, None, Some("claim")), config.clone()).await;
+        assert_eq!(body["gas_payment_motes"], config.default_gas_payment_motes);
+        let (_, body) = send_tx(relay_request(SENDER, None, None), config).await;
+        assert_eq!(body["gas_payment_motes"], 2_500_000_000u64);
+    }
+
+    #[tokio::test]
+    async fn explicit_gas_payment_overrides_the_default() {
+        let config = FacilitatorConfig {
+            entry_point_gas_payment_motes: std::collections::BTreeMap::from([("claim_batch".to_string(), 10_000_000_000)]),
+            ..FacilitatorConfig::default()
+        };
+
+        let (status, body) = send_tx(relay_request(SENDER, Some(3_000_000_000), Some("claim_batch")), config.clone()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["gas_payment_motes"], 3_000_000_000u64);
+
+        let (status, body) = send_tx(relay_request(SENDER, Some(config.min_gas_payment_motes - 1), None), config).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].as_str().unwrap().contains("below the minimum"));
+    }
+
+    /// Status and JSON body of an `/estimate_tx_fees` call with no node
+    async fn estimate(request: EstimateFeeRequest, config: FacilitatorConfig) -> (StatusCode, serde_json::Value) {
+        let reply = match estimate_fees_handler(
+            request,
+            node::unreachable_node(),
+            Arc::new(RwLock::new(config)),
+            Arc::new(DegradedMode::new(false)),
+        )
+        .await
+        {
+            Ok(reply) => reply,
+            Err(never) => match never {},
+        };
+        let response = warp::Reply::into_response(reply);
+        let status = response.status();
+        let body = warp::hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    fn estimate_request(fee_token: Option<String>) -> EstimateFeeRequest {
+        EstimateFeeRequest {
+            transaction_size: None,
+            instruction_count: Some(3),
+            batch_size: None,
+            claim_deploy: None,
+            uses_lookup_tables: None,
+            is_payment_required: None,
+            fee_token,
+            payer: None,
+        }
+    }
+
+    async fn estimate_breakdown(batch_size: u32) -> serde_json::Value {
+        let request = EstimateFeeRequest {
+            batch_size: Some(batch_size),
+            claim_deploy: Some(serde_json::json!({})),
+            ..estimate_request(None)
+        };
+        estimate(request, FacilitatorConfig::default()).await.1["breakdown"].clone()
+    }
+
+    #[tokio::test]
+    async fn requested_fee_token_needs_a_configured_rate() {
+        let fee_token = format!("hash-{}", TOKEN_CONTRACT);
+        let (status, body) = estimate(estimate_request(Some(fee_token.clone())), FacilitatorConfig::default()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].as_str().unwrap().contains("no configured rate"), "{}", body);
+
+        let config = FacilitatorConfig {
+            fee_token_rates: std::collections::BTreeMap::from([(fee_token.clone(), 1_000.0)]),
+            ..FacilitatorConfig::default()
+        };
+        let (status, body) = estimate(estimate_request(Some(fee_token)), config).await;
+        assert_eq!(status, StatusCode::OK);
+        let fee_motes = body["fee_in_lamports"].as_u64().unwrap();
+        assert_eq!(body["fee_in_token"].as_u64().unwrap(), fee_motes.div_ceil(1_000));
+    }
+
+    #[tokio::test]
+    async fn batch_estimate_falls_back_to_rates_without_the_node() {
+        let single = estimate_breakdown(1).await;
+        let batch = estimate_breakdown(4).await;
+
+        assert_eq!(single["simulated"], false);
+        assert_eq!(single["instruction_fee"], 3 * FacilitatorConfig::default().fee_rates.instruction_rate);
+        assert_eq!(batch["instruction_fee"].as_u64().unwrap(), 4 * single["instruction_fee"].as_u64().unwrap());
+        assert_eq!(batch["base_fee"], single["base_fee"]);
+    }
+
+    /// Status and JSON body of a `/sign_tx` call against a two-key pool
+    async fn sign_tx(signer_key: Option<&str>) -> (StatusCode, serde_json::Value) {
+        let entry = |public_key: &str, weight| signers::SignerEntry {
+            public_key: public_key.to_string(),
+            weight,
+        };
+        let signer_pool = SignerPool::new(vec![entry("01aa", 1), entry("01bb", 3)], std::time::Duration::from_secs(1));
+        let request = SignTransactionRequest {
+            transaction: "deploy".to_string(),
+            signer_key: signer_key.map(str::to_string),
+            sig_verify: None,
+            gas_payment_motes: None,
+            entry_point: None,
+            payer: None,
+        };
+        let reply = match sign_transaction_handler(request, Arc::new(signer_pool)).await {
+            Ok(reply) => reply,
+            Err(never) => match never {},
+        };
+        let response = warp::Reply::into_response(reply);
+        let status = response.status();
+        let body = warp::hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn pool_signers_may_be_requested() {
+        let (status, body) = sign_tx(Some("01AA")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["signer_pubkey"], "01aa");
+        assert_eq!(body["fallback_used"], false);
+    }
+
+    #[tokio::test]
+    async fn keys_outside_the_pool_are_refused() {
+        let (status, body) = sign_tx(Some("01cc")).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["error"], "Signer key is not in the signer pool");
+    }
+
+    #[tokio::test]
+    async fn omitted_signer_is_picked_from_the_pool() {
+        let (status, body) = sign_tx(None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(["01aa", "01bb"].contains(&body["signer_pubkey"].as_str().unwrap()));
+    }
+
+    #[test]
+    fn default_deadline_follows_block_time() {
+        let issued_at = 1_700_000_000_000;
+        assert_eq!(claim_deadline(None, issued_at, DEFAULT_DEADLINE_SECS), 1_700_000_300_000);
+        assert_eq!(claim_deadline(Some(1_700_000_010_000), issued_at, DEFAULT_DEADLINE_SECS), 1_700_000_010_000);
+        assert_eq!(claim_deadline(None, u64::MAX - 1, DEFAULT_DEADLINE_SECS), u64::MAX);
+    }
+
+    #[tokio::test]
+    async fn timestamps_beyond_the_skew_are_rejected() {
+        let node = node::unreachable_node();
+        let nonce_cache = NonceCache::new(std::time::Duration::ZERO);
+        let mut reads = NodeReads::new(&node, &nonce_cache);
+        reads.block_time_ms = Some(1_700_000_000_500);
+        let node_time = 1_700_000_000;
+
+        for timestamp in [node_time, node_time - DEFAULT_MAX_CLOCK_SKEW_SECS, node_time + DEFAULT_MAX_CLOCK_SKEW_SECS] {
+            assert_eq!(check_clock_skew(&mut reads, timestamp).await.unwrap(), None);
+        }
+        for timestamp in [node_time - DEFAULT_MAX_CLOCK_SKEW_SECS - 1, node_time + DEFAULT_MAX_CLOCK_SKEW_SECS + 1] {
+            let (failure_reason, reason) = check_clock_skew(&mut reads, timestamp).await.unwrap().unwrap();
+            assert_eq!(failure_reason, FailureReason::ClockSkew);
+            assert!(reason.contains("from node time 1700000000"), "{}", reason);
+        }
+    }
+
+    #[tokio::test]
+    async fn skewed_timestamp_fails_with_clock_skew() {
+        let node = node::unreachable_node();
+        let nonce_cache = NonceCache::new(std::time::Duration::ZERO);
+        let mut reads = NodeReads::new(&node, &nonce_cache);
+        reads.block_time_ms = Some(1_700_000_000_000);
+
+        let mut request = payment("deploy-1", RECIPIENT, "1000");
+        request.timestamp = Some(1_700_000_000 + DEFAULT_MAX_CLOCK_SKEW_SECS + 1);
+        let response = match verify_payment(request, &mut reads, &IssuedClaims::new(None), &FacilitatorConfig::default()).await {
+            Ok(response) => response,
+            Err(_) => panic!("verification failed"),
+        };
+        assert!(!response.valid);
+        assert_eq!(response.failure_reason, FailureReason::ClockSkew);
+    }
+
+    #[tokio::test]
+    async fn passed_deadline_fails_with_expired_deadline() {
+        let node = node::unreachable_node();
+        let nonce_cache = NonceCache::new(std::time::Duration::ZERO);
+        let mut reads = NodeReads::new(&node, &nonce_cache);
+        reads.block_time_ms = Some(1_700_000_000_000);
+
+        let mut expired = payment("deploy-1", RECIPIENT, "1000");
+        expired.deadline = Some(1_699_999_999_999);
+        let mut current = payment("deploy-2", RECIPIENT, "1000");
+        current.deadline = Some(1_700_000_000_000);
+
+        let config = FacilitatorConfig::default();
+        let expired = match verify_payment(expired, &mut reads, &IssuedClaims::new(None), &config).await {
+            Ok(response) => response,
+            Err(_) => panic!("verification failed"),
+        };
+        assert_eq!(expired.failure_reason, FailureReason::ExpiredDeadline);
+        let current = match verify_payment(current, &mut reads, &IssuedClaims::new(None), &config).await {
+            Ok(response) => response,
+            Err(_) => panic!("verification failed"),
+        };
+        assert!(current.valid);
+    }
+
+    #[tokio::test]
+    async fn unreachable_node_fails_with_node_unavailable() {
+        let node = node::unreachable_node();
+        let nonce_cache = NonceCache::new(std::time::Duration::ZERO);
+        let mut reads = NodeReads::new(&node, &nonce_cache);
+
+        let mut request = payment("deploy-1", RECIPIENT, "1000");
+        request.timestamp = Some(1_700_000_000);
+        let response = match verify_payment(request, &mut reads, &IssuedClaims::new(None), &FacilitatorConfig::default()).await {
+            Ok(response) => response,
+            Err(_) => panic!("verification failed"),
+        };
+        assert!(!response.valid);
+        assert_eq!(response.failure_reason, FailureReason::NodeUnavailable);
+    }
+
+    #[test]
+    fn mismatched_claim_nonce_is_flagged() {
+        assert_eq!(nonce_mismatch_warning(4, 4), None);
+        let warning = nonce_mismatch_warning(3, 4).unwrap();
+        assert!(warning.starts_with("Supplied nonce 3 does not match on-chain nonce 4"), "{}", warning);
+        assert!(nonce_mismatch_warning(5, 4).is_some());
+    }
+
+    const TOKEN_CONTRACT: &str = "6a545487ba47c62bdf02f68a9d8ada590fef2a1d28778dd5b346d63927e61b4a";
+
+    fn claim_request(recipient: &str, amount: &str, nonce: Option<u64>, payer: Option<&str>) -> ClaimMessageRequest {
+        ClaimMessageRequest {
+            recipient: recipient.to_string(),
+            amount: amount.to_string(),
+            nonce,
+            deadline: Some(1_700_000_300_000),
+            payer: payer.map(str::to_string),
+        }
+    }
+
+    async fn claim_message_status(request: ClaimMessageRequest) -> StatusCode {
+        claim_message_status_with(request, FacilitatorConfig::default()).await
+    }
+
+    async fn claim_message_status_with(request: ClaimMessageRequest, config: FacilitatorConfig) -> StatusCode {
+        // Every test sets the same value, so running them in parallel is fine
+        std::env::set_var("TOKEN_CONTRACT_HASH", TOKEN_CONTRACT);
+        let node = node::unreachable_node();
+        let nonce_cache = Arc::new(NonceCache::new(std::time::Duration::ZERO));
+        let reply = match claim_message_handler(request, node, Arc::new(RwLock::new(config)), nonce_cache, Arc::new(IssuedClaims::new(None))).await {
+            Ok(reply) => reply,
+            Err(never) => match never {},
+        };
+        warp::Reply::into_response(reply).status()
+    }
+
+    #[tokio::test]
+    async fn claim_message_rejects_malformed_input() {
+        assert_eq!(
+            claim_message_status(claim_request("not-an-account", "1000", Some(0), None)).await,
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            claim_message_status(claim_request(RECIPIENT, "1.5", Some(0), None)).await,
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            claim_message_status(claim_request(RECIPIENT, "1000", Some(0), Some("not-an-account"))).await,
+            StatusCode::BAD_REQUEST
+        );
+        // Without a nonce the payer is needed to look it up
+        assert_eq!(
+            claim_message_status(claim_request(RECIPIENT, "1000", None, None)).await,
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[tokio::test]
+    async fn claim_message_enforces_the_token_amount_limits() {
+        let config = FacilitatorConfig {
+            token_amount_limits: std::collections::BTreeMap::from([(
+                format!("hash-{}", TOKEN_CONTRACT),
+                config::AmountLimits {
+                    min_amount: Some("1000".to_string()),
+                    max_amount: Some("5000".to_string()),
+                },
+            )]),
+            ..FacilitatorConfig::default()
+        };
+        for amount in ["999", "5001", "0"] {
+            assert_eq!(
+                claim_message_status_with(claim_request(RECIPIENT, amount, Some(0), None), config.clone()).await,
+                StatusCode::BAD_REQUEST,
+                "{}",
+                amount
+            );
+        }
+        // In range, the request gets as far as reading the block time
+        for amount in ["1000", "05000"] {
+            assert_eq!(
+                claim_message_status_with(claim_request(RECIPIENT, amount, Some(0), None), config.clone()).await,
+                StatusCode::BAD_GATEWAY,
+                "{}",
+                amount
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn claim_message_needs_the_node_for_block_time() {
+        assert_eq!(
+            claim_message_status(claim_request(RECIPIENT, "1000", Some(0), None)).await,
+            StatusCode::BAD_GATEWAY
+        );
+        assert_eq!(
+            claim_message_status(claim_request(RECIPIENT, "1000", None, Some(SENDER))).await,
+            StatusCode::BAD_GATEWAY
+        );
+    }
+
+    const PERMIT_VECTORS: &str = include_str!("../tests/fixtures/permit_vectors.json");
+
+    fn permit_vector(name: &str) -> serde_json::Value {
+        let fixture: serde_json::Value = serde_json::from_str(PERMIT_VECTORS).unwrap();
+        fixture["vectors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|vector| vector["name"] == name)
+            .cloned()
+            .unwrap()
+    }
+
+    fn hex_hash(hex: &str) -> [u8; 32] {
+        hex::decode(hex).unwrap().try_into().unwrap()
+    }
+
+    /// A payment signed the way `vector` says, with no sender account
+    fn signed_payment(vector: &serde_json::Value) -> PaymentVerificationRequest {
+        PaymentVerificationRequest {
+            sender: None,
+            public_key: Some(vector["public_key"].as_str().unwrap().to_string()),
+            signature: Some(vector["signature"].as_str().unwrap().to_string()),
+            nonce: vector["nonce"].as_u64(),
+            deadline: vector["deadline"].as_u64(),
+            ..payment(
+                "deploy-1",
+                &format!("account-hash-{}", vector["recipient"].as_str().unwrap()),
+                vector["amount"].as_str().unwrap(),
+            )
+        }
+    }
+
+    #[test]
+    fn signatures_are_checked_against_the_rebuilt_claim_message() {
+        for name in ["ed25519_basic", "ed25519_tampered_amount", "secp256k1_basic", "secp256k1_signature_for_ed25519_key"] {
+            let vector = permit_vector(name);
+            let request = signed_payment(&vector);
+            let contract_hash = hashes::ContractHash(hex_hash(vector["contract_hash"].as_str().unwrap()));
+            let failure = signature_failure(
+                &request,
+                request.public_key.as_deref().unwrap(),
+                request.signature.as_deref().unwrap(),
+                request.amount.parse().unwrap(),
+                vector["chain_name"].as_str().unwrap(),
+                &contract_hash,
+            );
+            let expected_valid = vector["expected_valid"].as_bool().unwrap();
+            assert_eq!(failure.map(|(reason, _)| reason), (!expected_valid).then_some(FailureReason::BadSignature), "{}", name);
+        }
+
+        // A valid signature does not carry over to another chain
+        let vector = permit_vector("ed25519_basic");
+        let request = signed_payment(&vector);
+        let contract_hash = hashes::ContractHash(hex_hash(TOKEN_CONTRACT));
+        let failure = signature_failure(
+            &request,
+            request.public_key.as_deref().unwrap(),
+            request.signature.as_deref().unwrap(),
+            request.amount.parse().unwrap(),
+            "casper",
+            &contract_hash,
+        );
+        assert_eq!(failure.map(|(reason, _)| reason), Some(FailureReason::BadSignature));
+    }
+
+    async fn verify_signed(request: PaymentVerificationRequest) -> PaymentVerificationResponse {
+        verify_signed_at(request, &IssuedClaims::new(None), 0).await
+    }
+
+    /// Verify `request` when the node's block time is `now_ms`
+    async fn verify_signed_at(
+        request: PaymentVerificationRequest,
+        issued_claims: &IssuedClaims,
+        now_ms: u64,
+    ) -> PaymentVerificationResponse {
+        std::env::set_var("TOKEN_CONTRACT_HASH", TOKEN_CONTRACT);
+        let node = node::unreachable_node();
+        let nonce_cache = NonceCache::new(std::time::Duration::ZERO);
+        let mut reads = NodeReads::new(&node, &nonce_cache);
+        reads.block_time_ms = Some(now_ms);
+        match verify_payment(request, &mut reads, issued_claims, &FacilitatorConfig::default()).await {
+            Ok(response) => response,
+            Err(_) => panic!("verification failed"),
+        }
+    }
+
+    #[tokio::test]
+    async fn payments_need_a_signature_over_their_claim_message() {
+        let vector = permit_vector("secp256k1_min_values");
+        let response = verify_signed(signed_payment(&vector)).await;
+        assert_eq!((response.valid, response.failure_reason), (true, FailureReason::None), "{}", response.message);
+
+        let tampered = PaymentVerificationRequest { amount: "2".to_string(), ..signed_payment(&vector) };
+        assert_eq!(verify_signed(tampered).await.failure_reason, FailureReason::BadSignature);
+
+        let wrong_key = signed_payment(&permit_vector("secp256k1_signature_for_ed25519_key"));
+        let response = verify_signed(wrong_key).await;
+        assert_eq!((response.valid, response.failure_reason), (false, FailureReason::BadSignature));
+
+        let unsigned_nonce = PaymentVerificationRequest { nonce: None, ..signed_payment(&vector) };
+        assert_eq!(verify_signed(unsigned_nonce).await.failure_reason, FailureReason::MalformedInput);
+    }
+
+    #[tokio::test]
+    async fn signature_age_runs_from_when_the_message_was_issued() {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let issued_at = 1_700_000_000_000;
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let request = || PaymentVerificationRequest {
+            sender: None,
+            public_key: Some(format!("01{}", hex::encode(key.verifying_key().as_bytes()))),
+            nonce: Some(3),
+            deadline: Some(issued_at + 300_000),
+            ..payment("deploy-1", RECIPIENT, "1000")
+        };
+        let contract_hash = hashes::ContractHash(hex_hash(TOKEN_CONTRACT));
+        let message = signed_claim_message(&request(), 1000, &chain_name(), &contract_hash).unwrap();
+        let signed = || PaymentVerificationRequest {
+            signature: Some(hex::encode(key.sign(message.as_bytes()).to_bytes())),
+            ..request()
+        };
+
+        let claims = IssuedClaims::new(Some(60));
+        let outcome = |response: PaymentVerificationResponse| (response.valid, response.failure_reason);
+        assert_eq!(
+            outcome(verify_signed_at(signed(), &claims, issued_at).await),
+            (false, FailureReason::StaleSignature),
+            "messages this server never issued have no signing time"
+        );
+
+        let message_hash = message::message_hash(&message);
+        claims.issue(message_hash, issued_at, issued_at + 300_000);
+        assert_eq!(outcome(verify_signed_at(signed(), &claims, issued_at + 60_000).await), (true, FailureReason::None));
+        assert_eq!(
+            outcome(verify_signed_at(signed(), &claims, issued_at + 61_000).await),
+            (false, FailureReason::StaleSignature)
+        );
+
+        // Asking for the message again does not make the old signature fresh
+        assert_eq!(claims.issue(message_hash, issued_at + 61_000, issued_at + 300_000), issued_at);
+        assert_eq!(
+            outcome(verify_signed_at(signed(), &claims, issued_at + 61_000).await),
+            (false, FailureReason::StaleSignature)
+        );
+
+        // Nor can the client claim a later signing time
+        let mut resent = serde_json::json!({
+            "deploy_hash": "deploy-1",
+            "amount": "1000",
+            "recipient": RECIPIENT,
+            "public_key": request().public_key,
+            "signature": signed().signature,
+            "nonce": 3,
+            "deadline": issued_at + 300_000,
+        });
+        resent["signed_at"] = serde_json::json!(issued_at + 61_000);
+        assert!(serde_json::from_value::<PaymentVerificationRequest>(resent).is_err());
+    }
+}
@@ -0,0 +1,172 @@
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+
+use crate::x402_message;
+
+/// Build the canonical claim message a payer must sign; see
+/// [`x402_message::build`] for the layout
+pub fn build_claim_message(
+    chain_name: &str,
+    contract_hash: &str,
+    recipient: &str,
+    amount: &str,
+    nonce: u64,
+    deadline: u64,
+) -> String {
+    x402_message::build(
+        chain_name,
+        contract_hash,
+        recipient,
+        amount,
+        nonce,
+        deadline,
+        x402_message::CLAIM_ACTION_TAG,
+    )
+}
+
+/// Blake2b-256 digest of the message bytes, as computed on-chain
+pub fn message_hash(message: &str) -> [u8; 32] {
+    let mut hasher = Blake2b::<U32>::new();
+    hasher.update(message.as_bytes());
+    hasher.finalize().into()
+}
+
+/// Whether `signature_hex` is `public_key_hex`'s signature over `message`.
+///
+/// The key is a Casper hex public key: a tag byte (`01` Ed25519, `02`
+/// secp256k1) and the key bytes. The signature is 64 raw bytes, optionally
+/// behind the same tag byte. As in Casper and the contract, Ed25519 signs
+/// the message bytes (checked strictly) and secp256k1 their SHA-256 digest,
+/// with only low-S signatures accepted.
+pub fn verify_claim_signature(message: &str, public_key_hex: &str, signature_hex: &str) -> bool {
+    let (Ok(public_key), Ok(signature)) = (hex::decode(public_key_hex), hex::decode(signature_hex)) else {
+        return false;
+    };
+    let Some((&key_tag, key)) = public_key.split_first() else {
+        return false;
+    };
+    let signature = match signature.split_first() {
+        Some((&tag, raw)) if signature.len() == 65 && tag == key_tag => raw,
+        _ if signature.len() == 64 => &signature[..],
+        _ => return false,
+    };
+
+    match key_tag {
+        0x01 => {
+            use ed25519_dalek::{Signature, VerifyingKey};
+            let Ok(key) = <[u8; 32]>::try_from(key) else {
+                return false;
+            };
+            let (Ok(key), Ok(signature)) = (VerifyingKey::from_bytes(&key), Signature::from_slice(signature)) else {
+                return false;
+            };
+            key.verify_strict(message.as_bytes(), &signature).is_ok()
+        }
+        0x02 => {
+            use k256::ecdsa::signature::Verifier;
+            use k256::ecdsa::{Signature, VerifyingKey};
+            let (Ok(key), Ok(signature)) = (VerifyingKey::from_sec1_bytes(key), Signature::from_slice(signature)) else {
+                return false;
+            };
+            key.verify(message.as_bytes(), &signature).is_ok()
+        }
+        _ => false,
+    }
+}
+
+/// Reject a signature made longer than `max_age_secs` before `now_ms`.
+///
+/// `issued_at_ms` is when this server issued the signed claim message, in
+/// the same millisecond block-time basis; a signature cannot predate it.
+pub fn signature_age_failure(issued_at_ms: u64, now_ms: u64, max_age_secs: u64) -> Option<String> {
+    let age_ms = now_ms.saturating_sub(issued_at_ms);
+    if age_ms > max_age_secs.saturating_mul(1000) {
+        return Some(format!(
+            "Signature was made {}s ago, more than the allowed {}s; request a new claim message and re-sign",
+            age_ms / 1000,
+            max_age_secs
+        ));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_signature_is_accepted() {
+        let now_ms = 1_700_000_060_000;
+        assert_eq!(signature_age_failure(1_700_000_000_000, now_ms, 60), None);
+        assert_eq!(signature_age_failure(now_ms + 5_000, now_ms, 60), None);
+    }
+
+    #[test]
+    fn stale_signature_is_rejected() {
+        let now_ms = 1_700_000_060_001;
+        let reason = signature_age_failure(1_700_000_000_000, now_ms, 60).unwrap();
+        assert!(reason.contains("more than the allowed 60s"), "{}", reason);
+    }
+
+    const PERMIT_VECTORS: &str = include_str!("../tests/fixtures/permit_vectors.json");
+
+    #[test]
+    fn permit_vectors_match_canonical_message() {
+        let fixture: serde_json::Value = serde_json::from_str(PERMIT_VECTORS).unwrap();
+        for vector in fixture["vectors"].as_array().unwrap() {
+            let name = vector["name"].as_str().unwrap();
+            let message = build_claim_message(
+                vector["chain_name"].as_str().unwrap(),
+                vector["contract_hash"].as_str().unwrap(),
+                vector["recipient"].as_str().unwrap(),
+                vector["amount"].as_str().unwrap(),
+                vector["nonce"].as_u64().unwrap(),
+                vector["deadline"].as_u64().unwrap(),
+            );
+            assert_eq!(message, vector["message"].as_str().unwrap(), "{}", name);
+            assert_eq!(
+                hex::encode(message_hash(&message)),
+                vector["message_hash"].as_str().unwrap(),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn permit_vector_signatures_verify_as_expected() {
+        let fixture: serde_json::Value = serde_json::from_str(PERMIT_VECTORS).unwrap();
+        for vector in fixture["vectors"].as_array().unwrap() {
+            let name = vector["name"].as_str().unwrap();
+            let public_key = vector["public_key"].as_str().unwrap();
+            let signature = vector["signature"].as_str().unwrap();
+            let verified = verify_claim_signature(vector["message"].as_str().unwrap(), public_key, signature);
+            assert_eq!(verified, vector["expected_valid"].as_bool().unwrap(), "{}", name);
+
+            // Casper's tagged form is accepted with the key's own tag only
+            let tagged = format!("{}{}", &public_key[..2], signature);
+            assert_eq!(
+                verify_claim_signature(vector["message"].as_str().unwrap(), public_key, &tagged),
+                verified,
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_keys_and_signatures_do_not_verify() {
+        let fixture: serde_json::Value = serde_json::from_str(PERMIT_VECTORS).unwrap();
+        let vector = &fixture["vectors"][0];
+        let message = vector["message"].as_str().unwrap();
+        let public_key = vector["public_key"].as_str().unwrap();
+        let signature = vector["signature"].as_str().unwrap();
+        assert!(verify_claim_signature(message, public_key, signature));
+
+        assert!(!verify_claim_signature(message, public_key, &format!("02{}", signature)));
+        assert!(!verify_claim_signature(message, public_key, &signature[2..]));
+        assert!(!verify_claim_signature(message, &format!("03{}", &public_key[2..]), signature));
+        assert!(!verify_claim_signature(message, "", signature));
+        assert!(!verify_claim_signature(message, public_key, "zz"));
+    }
+}
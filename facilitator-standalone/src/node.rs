@@ -0,0 +1,476 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use tokio::sync::Semaphore;
+
+const DEFAULT_NODE_URL: &str = "http://127.0.0.1:7777/rpc";
+const DEFAULT_MAX_CONCURRENT_CALLS: usize = 16;
+const DEFAULT_QUEUE_TIMEOUT_MS: u64 = 2_000;
+const DEFAULT_REQUEST_TIMEOUT_MS: u64 = 10_000;
+
+#[derive(Debug)]
+pub enum NodeError {
+    /// The node could not be reached or returned a non-JSON body
+    Transport(String),
+    /// The node answered with a JSON-RPC error object
+    Rpc(String),
+    /// The node answered but the result did not have the expected shape
+    InvalidResponse(String),
+    /// Every call slot stayed busy for the whole queue timeout
+    Busy,
+}
+
+impl NodeError {
+    /// Whether the node could not be reached at all, as opposed to
+    /// answering with an error
+    pub fn is_unreachable(&self) -> bool {
+        matches!(self, NodeError::Transport(_))
+    }
+}
+
+impl fmt::Display for NodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeError::Transport(msg) => write!(f, "node transport error: {}", msg),
+            NodeError::Rpc(msg) => write!(f, "node rpc error: {}", msg),
+            NodeError::InvalidResponse(msg) => write!(f, "invalid node response: {}", msg),
+            NodeError::Busy => write!(f, "node call queue is full"),
+        }
+    }
+}
+
+/// Minimal JSON-RPC client for the Casper node.
+///
+/// Clones share one call limiter, so at most `max_concurrent_calls` RPC
+/// requests are in flight across all handlers; further calls wait up to
+/// `queue_timeout` for a slot and then fail with [`NodeError::Busy`]. A
+/// request the node does not answer within `request_timeout` fails with
+/// [`NodeError::Transport`] and frees its slot.
+#[derive(Clone)]
+pub struct NodeClient {
+    rpc_url: String,
+    http: reqwest::Client,
+    limiter: Arc<Semaphore>,
+    max_concurrent_calls: usize,
+    queue_timeout: Duration,
+}
+
+impl NodeClient {
+    pub fn new(
+        rpc_url: String,
+        max_concurrent_calls: usize,
+        queue_timeout: Duration,
+        request_timeout: Duration,
+    ) -> Self {
+        let max_concurrent_calls = max_concurrent_calls.max(1);
+        let http = reqwest::Client::builder()
+            .timeout(request_timeout)
+            .build()
+            .expect("failed to build node HTTP client");
+        Self {
+            rpc_url,
+            http,
+            limiter: Arc::new(Semaphore::new(max_concurrent_calls)),
+            max_concurrent_calls,
+            queue_timeout,
+        }
+    }
+
+    /// Build a client from `CASPER_NODE_URL` (falling back to a local node),
+    /// `FACILITATOR_MAX_NODE_CONCURRENCY`, `FACILITATOR_NODE_QUEUE_TIMEOUT_MS`
+    /// and `FACILITATOR_NODE_REQUEST_TIMEOUT_MS`
+    pub fn from_env() -> Self {
+        let rpc_url = std::env::var("CASPER_NODE_URL").unwrap_or_else(|_| DEFAULT_NODE_URL.to_string());
+
+        let max_concurrent_calls = std::env::var("FACILITATOR_MAX_NODE_CONCURRENCY")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|n| *n > 0)
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_CALLS);
+
+        let queue_timeout_ms = std::env::var("FACILITATOR_NODE_QUEUE_TIMEOUT_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_QUEUE_TIMEOUT_MS);
+
+        let request_timeout_ms = std::env::var("FACILITATOR_NODE_REQUEST_TIMEOUT_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|ms| *ms > 0)
+            .unwrap_or(DEFAULT_REQUEST_TIMEOUT_MS);
+
+        Self::new(
+            rpc_url,
+            max_concurrent_calls,
+            Duration::from_millis(queue_timeout_ms),
+            Duration::from_millis(request_timeout_ms),
+        )
+    }
+
+    pub fn rpc_url(&self) -> &str {
+        &self.rpc_url
+    }
+
+    pub fn max_concurrent_calls(&self) -> usize {
+        self.max_concurrent_calls
+    }
+
+    /// Perform a JSON-RPC call and return the `result` member
+    pub async fn call(&self, method: &str, params: Value) -> Result<Value, NodeError> {
+        let _permit = match tokio::time::timeout(self.queue_timeout, self.limiter.acquire()).await {
+            Ok(Ok(permit)) => permit,
+            // The semaphore is never closed, so only the timeout can fail here
+            _ => return Err(NodeError::Busy),
+        };
+
+        let body = json!({
+            "id": 1,
+            "jsonrpc": "2.0",
+            "method": method,
+            "params": params,
+        });
+
+        let response: Value = self
+            .http
+            .post(&self.rpc_url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| NodeError::Transport(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| NodeError::Transport(e.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(NodeError::Rpc(error.to_string()));
+        }
+
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| NodeError::InvalidResponse(format!("{} returned no result", method)))
+    }
+
+    /// Get the main purse balance (in motes) of an `account-hash-<hex>` account
+    pub async fn get_account_balance(&self, account_hash: &str) -> Result<u128, NodeError> {
+        let result = self
+            .call(
+                "query_balance",
+                json!({
+                    "purse_identifier": {
+                        "main_purse_under_account_hash": account_hash
+                    }
+                }),
+            )
+            .await?;
+
+        result
+            .get("balance")
+            .and_then(Value::as_str)
+            .and_then(|balance| balance.parse::<u128>().ok())
+            .ok_or_else(|| NodeError::InvalidResponse("missing or malformed balance".to_string()))
+    }
+
+    /// Get the latest global state root hash
+    pub async fn get_state_root_hash(&self) -> Result<String, NodeError> {
+        let result = self.call("chain_get_state_root_hash", json!({})).await?;
+        result
+            .get("state_root_hash")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| NodeError::InvalidResponse("missing state_root_hash".to_string()))
+    }
+
+    /// Read an item from a dictionary stored under a contract's named keys.
+    ///
+    /// Missing items are reported by the node as an RPC error; they are
+    /// returned as `None`.
+    pub async fn get_dictionary_value(
+        &self,
+        contract_hash: &str,
+        dictionary_name: &str,
+        item_key: &str,
+    ) -> Result<Option<Value>, NodeError> {
+        let state_root_hash = self.get_state_root_hash().await?;
+        let result = self
+            .call(
+                "state_get_dictionary_item",
+                json!({
+                    "state_root_hash": state_root_hash,
+                    "dictionary_identifier": {
+                        "ContractNamedKey": {
+                            "key": format!("hash-{}", contract_hash),
+                            "dictionary_name": dictionary_name,
+                            "dictionary_item_key": item_key
+                        }
+                    }
+                }),
+            )
+            .await;
+
+        let result = match result {
+            Ok(result) => result,
+            Err(NodeError::Rpc(msg)) if msg.contains("ValueNotFound") || msg.contains("value not found") => {
+                return Ok(None)
+            }
+            Err(e) => return Err(e),
+        };
+
+        result
+            .pointer("/stored_value/CLValue/parsed")
+            .cloned()
+            .map(Some)
+            .ok_or_else(|| NodeError::InvalidResponse(format!("missing {} value", dictionary_name)))
+    }
+
+    /// Read an account's permit nonce from the token's `nonces` dictionary.
+    ///
+    /// Accounts that have never claimed have no dictionary entry; their
+    /// nonce is reported as 0.
+    pub async fn get_account_nonce(
+        &self,
+        token_contract_hash: &str,
+        account_hash: &str,
+    ) -> Result<u64, NodeError> {
+        let parsed = match self
+            .get_dictionary_value(token_contract_hash, "nonces", account_hash)
+            .await?
+        {
+            Some(parsed) => parsed,
+            None => return Ok(0),
+        };
+
+        parsed
+            .as_u64()
+            .or_else(|| parsed.as_str().and_then(|s| s.parse().ok()))
+            .ok_or_else(|| NodeError::InvalidResponse("malformed nonce value".to_string()))
+    }
+
+    /// Read an account's balance from a token's `balances` dictionary.
+    ///
+    /// Accounts without an entry hold nothing. Balances above `u128::MAX`
+    /// (only possible for absurd supplies) are reported as malformed.
+    pub async fn get_token_balance(
+        &self,
+        token_contract_hash: &str,
+        account_hash: &str,
+    ) -> Result<u128, NodeError> {
+        let parsed = match self
+            .get_dictionary_value(token_contract_hash, "balances", account_hash)
+            .await?
+        {
+            Some(parsed) => parsed,
+            None => return Ok(0),
+        };
+
+        parsed
+            .as_u64()
+            .map(u128::from)
+            .or_else(|| parsed.as_str().and_then(|s| s.parse().ok()))
+            .ok_or_else(|| NodeError::InvalidResponse("malformed balance value".to_string()))
+    }
+
+    /// Read a named-key value stored directly under a contract
+    pub async fn get_contract_named_value(
+        &self,
+        contract_hash: &str,
+        name: &str,
+    ) -> Result<Value, NodeError> {
+        let result = self
+            .call(
+                "query_global_state",
+                json!({
+                    "state_identifier": null,
+                    "key": format!("hash-{}", contract_hash),
+                    "path": [name]
+                }),
+            )
+            .await?;
+
+        result
+            .pointer("/stored_value/CLValue/parsed")
+            .cloned()
+            .ok_or_else(|| NodeError::InvalidResponse(format!("{} is not a CLValue", name)))
+    }
+
+    /// Read a CEP-18 token's `decimals` and `symbol` named keys
+    pub async fn get_token_display_info(&self, contract_hash: &str) -> Result<(u8, String), NodeError> {
+        let decimals = self
+            .get_contract_named_value(contract_hash, "decimals")
+            .await?
+            .as_u64()
+            .and_then(|d| u8::try_from(d).ok())
+            .ok_or_else(|| NodeError::InvalidResponse("malformed decimals".to_string()))?;
+
+        let symbol = self
+            .get_contract_named_value(contract_hash, "symbol")
+            .await?
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| NodeError::InvalidResponse("malformed symbol".to_string()))?;
+
+        Ok((decimals, symbol))
+    }
+
+    /// Read the facilitator contract's `is_paused` flag and `pause_reason`
+    pub async fn get_pause_state(&self, contract_hash: &str) -> Result<(bool, Option<String>), NodeError> {
+        let paused = self.get_contract_named_value(contract_hash, "is_paused").await?;
+        let reason = self.get_contract_named_value(contract_hash, "pause_reason").await?;
+        parse_pause_state(&paused, reason)
+    }
+
+    /// Gas cost in motes of running `deploy` with `speculative_exec`.
+    ///
+    /// Fails with [`NodeError::Rpc`] on nodes that have speculative
+    /// execution disabled.
+    pub async fn speculative_exec_cost(&self, deploy: &Value) -> Result<u64, NodeError> {
+        let result = self.call("speculative_exec", json!({ "deploy": deploy })).await?;
+        parse_execution_cost(&result)
+    }
+
+    /// Timestamp of the latest block in milliseconds since the Unix epoch.
+    ///
+    /// This is the same time basis `runtime::get_blocktime()` exposes to
+    /// contracts, so deadlines derived from it are not skewed by the
+    /// server's wall clock.
+    pub async fn get_latest_block_time_ms(&self) -> Result<u64, NodeError> {
+        let result = self.call("chain_get_block", json!({})).await?;
+        let timestamp = result
+            .pointer("/block/header/timestamp")
+            .and_then(Value::as_str)
+            .ok_or_else(|| NodeError::InvalidResponse("missing block timestamp".to_string()))?;
+
+        parse_block_time_ms(timestamp)
+    }
+}
+
+/// Cost of a `speculative_exec` result; a failed execution still has one
+fn parse_execution_cost(result: &Value) -> Result<u64, NodeError> {
+    result
+        .pointer("/execution_result/Success/cost")
+        .or_else(|| result.pointer("/execution_result/Failure/cost"))
+        .and_then(Value::as_str)
+        .and_then(|cost| cost.parse().ok())
+        .ok_or_else(|| NodeError::InvalidResponse("missing execution cost".to_string()))
+}
+
+/// Milliseconds since the Unix epoch of an RFC 3339 block header timestamp
+fn parse_block_time_ms(timestamp: &str) -> Result<u64, NodeError> {
+    chrono::DateTime::parse_from_rfc3339(timestamp)
+        .map(|time| time.timestamp_millis() as u64)
+        .map_err(|e| NodeError::InvalidResponse(format!("malformed block timestamp: {}", e)))
+}
+
+/// Interpret the parsed `is_paused` and `pause_reason` CLValues
+fn parse_pause_state(paused: &Value, reason: Value) -> Result<(bool, Option<String>), NodeError> {
+    let paused = paused
+        .as_bool()
+        .ok_or_else(|| NodeError::InvalidResponse("malformed is_paused".to_string()))?;
+
+    let reason = match reason {
+        Value::Null => None,
+        Value::String(reason) => Some(reason),
+        _ => return Err(NodeError::InvalidResponse("malformed pause_reason".to_string())),
+    };
+
+    Ok((paused, reason))
+}
+
+/// A client whose node refuses every connection, so any read that reaches
+/// it fails
+#[cfg(test)]
+pub(crate) fn unreachable_node() -> NodeClient {
+    NodeClient::new("http://127.0.0.1:1/rpc".to_string(), 1, Duration::from_secs(1), Duration::from_secs(1))
+}
+
+/// A node that answers each request with the body `answer` returns at the
+/// time
+#[cfg(test)]
+pub(crate) async fn node_answering_with(answer: impl Fn() -> String + Send + 'static) -> NodeClient {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let rpc_url = format!("http://{}/rpc", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            // The JSON-RPC request body is the last thing sent
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            while !request.ends_with(b"}") {
+                match socket.read(&mut buf).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => request.extend_from_slice(&buf[..n]),
+                }
+            }
+            let body = answer();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+        }
+    });
+    NodeClient::new(rpc_url, 1, Duration::from_secs(1), Duration::from_secs(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn timed_out_request_frees_its_slot() {
+        // Accepts connections but never answers
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let rpc_url = format!("http://{}/rpc", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                connections.push(socket);
+            }
+        });
+
+        let node = NodeClient::new(rpc_url, 1, Duration::from_millis(50), Duration::from_millis(100));
+        for _ in 0..2 {
+            // Busy here would mean the first call kept the only slot
+            let result = node.call("info_get_status", json!({})).await;
+            assert!(matches!(result, Err(NodeError::Transport(_))), "{:?}", result);
+        }
+        assert_eq!(node.limiter.available_permits(), 1);
+    }
+
+    #[test]
+    fn execution_cost_is_read_from_either_outcome() {
+        let success = json!({ "execution_result": { "Success": { "cost": "2500000000" } } });
+        let failure = json!({ "execution_result": { "Failure": { "cost": "100", "error_message": "Out of gas" } } });
+        assert_eq!(parse_execution_cost(&success).unwrap(), 2_500_000_000);
+        assert_eq!(parse_execution_cost(&failure).unwrap(), 100);
+        assert!(matches!(parse_execution_cost(&json!({})), Err(NodeError::InvalidResponse(_))));
+    }
+
+    #[test]
+    fn block_time_is_read_in_milliseconds() {
+        assert_eq!(parse_block_time_ms("2023-11-14T22:13:20.123Z").unwrap(), 1_700_000_000_123);
+        assert_eq!(parse_block_time_ms("2023-11-14T23:13:20+01:00").unwrap(), 1_700_000_000_000);
+        assert!(matches!(parse_block_time_ms("1700000000"), Err(NodeError::InvalidResponse(_))));
+    }
+
+    #[test]
+    fn pause_state_carries_the_optional_reason() {
+        assert_eq!(parse_pause_state(&json!(false), Value::Null).unwrap(), (false, None));
+        assert_eq!(
+            parse_pause_state(&json!(true), json!("maintenance")).unwrap(),
+            (true, Some("maintenance".to_string()))
+        );
+        assert!(matches!(
+            parse_pause_state(&json!("yes"), Value::Null),
+            Err(NodeError::InvalidResponse(_))
+        ));
+        assert!(matches!(
+            parse_pause_state(&json!(true), json!(7)),
+            Err(NodeError::InvalidResponse(_))
+        ));
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::node::{NodeClient, NodeError};
+
+const DEFAULT_CACHE_SECS: u64 = 3;
+
+/// `(token contract hash, account hash)`
+type AccountKey = (String, String);
+
+/// How long after submitting a claim its account's nonce stays uncached if
+/// the node never shows it moving, e.g. because the claim failed
+const PENDING_CLAIM_SECS: u64 = 120;
+
+/// Briefly caches permit nonces per `(token contract, account)`.
+///
+/// A nonce only moves when a claim for the account lands. Once this server
+/// submits such a claim the account's entry is dropped, and node reads are
+/// not cached again until they show the nonce past its value at submission
+/// (or, if that value was unknown, until the claim has had time to land):
+/// until the claim executes the node still reports the old nonce, which
+/// must not be cached. Claims relayed elsewhere are only picked up once the
+/// entry expires, which is why the TTL is kept short.
+#[derive(Debug)]
+pub struct NonceCache {
+    ttl: Duration,
+    pending_ttl: Duration,
+    entries: Mutex<HashMap<AccountKey, (Instant, u64)>>,
+    /// Accounts with a submitted claim: when, and the nonce cached then
+    pending_claims: Mutex<HashMap<AccountKey, (Instant, Option<u64>)>>,
+}
+
+impl NonceCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            pending_ttl: Duration::from_secs(PENDING_CLAIM_SECS),
+            entries: Mutex::new(HashMap::new()),
+            pending_claims: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// TTL from `FACILITATOR_NONCE_CACHE_SECS` (0 disables caching)
+    pub fn from_env() -> Self {
+        let ttl_secs = std::env::var("FACILITATOR_NONCE_CACHE_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_CACHE_SECS);
+        Self::new(Duration::from_secs(ttl_secs))
+    }
+
+    /// The account's nonce, read from the node when not cached or expired
+    pub async fn get(
+        &self,
+        node: &NodeClient,
+        token_contract_hash: &str,
+        account_hash: &str,
+    ) -> Result<u64, NodeError> {
+        let key = (token_contract_hash.to_string(), account_hash.to_string());
+        if let Some((fetched_at, nonce)) = self.entries.lock().unwrap().get(&key) {
+            if fetched_at.elapsed() < self.ttl {
+                return Ok(*nonce);
+            }
+        }
+
+        let nonce = node.get_account_nonce(token_contract_hash, account_hash).await?;
+        if !self.ttl.is_zero() && self.claim_settled(&key, nonce) {
+            self.entries.lock().unwrap().insert(key, (Instant::now(), nonce));
+        }
+        Ok(nonce)
+    }
+
+    /// Drop the cached nonce of an account whose claim was just submitted,
+    /// and keep its node reads uncached until the claim lands
+    pub fn claim_submitted(&self, token_contract_hash: &str, account_hash: &str) {
+        let key = (token_contract_hash.to_string(), account_hash.to_string());
+        let cached = self.entries.lock().unwrap().remove(&key).map(|(_, nonce)| nonce);
+        self.pending_claims.lock().unwrap().insert(key, (Instant::now(), cached));
+    }
+
+    /// Whether `nonce`, just read from the node, may be cached: no claim of
+    /// the account is pending, or the nonce shows it landed, or it has been
+    /// pending too long to still land
+    fn claim_settled(&self, key: &AccountKey, nonce: u64) -> bool {
+        let mut pending_claims = self.pending_claims.lock().unwrap();
+        let settled = match pending_claims.get(key) {
+            None => return true,
+            Some((_, Some(nonce_at_submit))) if nonce > *nonce_at_submit => true,
+            Some((submitted_at, _)) => submitted_at.elapsed() >= self.pending_ttl,
+        };
+        if settled {
+            pending_claims.remove(key);
+        }
+        settled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{node_answering_with, unreachable_node};
+    use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    const TOKEN: &str = "6a545487ba47c62bdf02f68a9d8ada590fef2a1d28778dd5b346d63927e61b4a";
+    const ACCOUNT: &str = "fedcba9876543210fedcba9876543210fedcba9876543210fedcba9876543210";
+
+    fn cache_with_entry(ttl: Duration, nonce: u64) -> NonceCache {
+        let cache = NonceCache::new(ttl);
+        cache
+            .entries
+            .lock()
+            .unwrap()
+            .insert((TOKEN.to_string(), ACCOUNT.to_string()), (Instant::now(), nonce));
+        cache
+    }
+
+    #[tokio::test]
+    async fn cached_nonce_is_served_without_node_read() {
+        let cache = cache_with_entry(Duration::from_secs(60), 7);
+        assert_eq!(cache.get(&unreachable_node(), TOKEN, ACCOUNT).await.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn submitted_claim_invalidates_cached_nonce() {
+        let cache = cache_with_entry(Duration::from_secs(60), 7);
+        cache.claim_submitted(TOKEN, ACCOUNT);
+
+        let result = cache.get(&unreachable_node(), TOKEN, ACCOUNT).await;
+        assert!(matches!(result, Err(NodeError::Transport(_))));
+    }
+
+    #[tokio::test]
+    async fn old_nonce_read_before_the_claim_lands_is_not_cached() {
+        let node_nonce = Arc::new(AtomicU64::new(7));
+        let reads = Arc::new(AtomicUsize::new(0));
+        let node = {
+            let (node_nonce, reads) = (node_nonce.clone(), reads.clone());
+            node_answering_with(move || {
+                reads.fetch_add(1, Ordering::SeqCst);
+                format!(
+                    r#"{{"jsonrpc":"2.0","id":1,"result":{{"state_root_hash":"00","stored_value":{{"CLValue":{{"parsed":{}}}}}}}}}"#,
+                    node_nonce.load(Ordering::SeqCst)
+                )
+            })
+            .await
+        };
+        let cache = cache_with_entry(Duration::from_secs(60), 7);
+        cache.claim_submitted(TOKEN, ACCOUNT);
+
+        // Submitted but not executed: the node still has 7, and every read
+        // goes back to it
+        assert_eq!(cache.get(&node, TOKEN, ACCOUNT).await.unwrap(), 7);
+        let reads_before = reads.load(Ordering::SeqCst);
+        assert_eq!(cache.get(&node, TOKEN, ACCOUNT).await.unwrap(), 7);
+        assert!(reads.load(Ordering::SeqCst) > reads_before);
+
+        // Once the claim lands the new nonce is cached again
+        node_nonce.store(8, Ordering::SeqCst);
+        assert_eq!(cache.get(&node, TOKEN, ACCOUNT).await.unwrap(), 8);
+        node_nonce.store(9, Ordering::SeqCst);
+        let reads_before = reads.load(Ordering::SeqCst);
+        assert_eq!(cache.get(&node, TOKEN, ACCOUNT).await.unwrap(), 8);
+        assert_eq!(reads.load(Ordering::SeqCst), reads_before);
+    }
+
+    #[tokio::test]
+    async fn expired_nonce_is_read_again() {
+        let cache = cache_with_entry(Duration::from_millis(10), 7);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let result = cache.get(&unreachable_node(), TOKEN, ACCOUNT).await;
+        assert!(matches!(result, Err(NodeError::Transport(_))));
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt;
+use std::sync::OnceLock;
+
+use blake2::digest::consts::U4;
+use blake2::{Blake2b, Digest};
+
+/// Characters of a redacted value kept in log output
+const VISIBLE_PREFIX_CHARS: usize = 8;
+
+static REDACT_ENABLED: OnceLock<bool> = OnceLock::new();
+
+/// Whether sensitive values are redacted in log output.
+///
+/// On unless `FACILITATOR_LOG_REDACT` is `0` or `false`; read once.
+pub fn redaction_enabled() -> bool {
+    *REDACT_ENABLED.get_or_init(|| {
+        std::env::var("FACILITATOR_LOG_REDACT")
+            .map(|v| !(v == "0" || v.eq_ignore_ascii_case("false")))
+            .unwrap_or(true)
+    })
+}
+
+/// Wrap a signature, signer key or raw transaction for logging
+pub fn sensitive(value: &str) -> Redacted<'_> {
+    Redacted::new(value, redaction_enabled())
+}
+
+/// Displays a sensitive value either verbatim or as a short prefix plus a
+/// fingerprint, so log lines can still be correlated without carrying the
+/// value itself
+pub struct Redacted<'a> {
+    value: &'a str,
+    redact: bool,
+}
+
+impl<'a> Redacted<'a> {
+    pub fn new(value: &'a str, redact: bool) -> Self {
+        Self { value, redact }
+    }
+}
+
+impl fmt::Display for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.redact {
+            return f.write_str(self.value);
+        }
+
+        let prefix: String = self.value.chars().take(VISIBLE_PREFIX_CHARS).collect();
+        let fingerprint = Blake2b::<U4>::digest(self.value.as_bytes());
+        write!(
+            f,
+            "{}…[{} chars, fp {}]",
+            prefix,
+            self.value.chars().count(),
+            hex::encode(fingerprint)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNER_KEY: &str = "0179b5562e8fe654f94078b112e8a98ba7901f853ae695bed7e0e3910bad049664";
+    const SIGNATURE: &str = "01d2b1a4c5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f809";
+    const TRANSACTION: &str = "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9";
+
+    fn log_line(redact: bool) -> String {
+        format!(
+            "sign_tx: {} signed transaction {} (signature {})",
+            Redacted::new(SIGNER_KEY, redact),
+            Redacted::new(TRANSACTION, redact),
+            Redacted::new(SIGNATURE, redact)
+        )
+    }
+
+    #[test]
+    fn redacted_fields_do_not_appear_verbatim() {
+        let line = log_line(true);
+        for value in [SIGNER_KEY, SIGNATURE, TRANSACTION] {
+            assert!(!line.contains(value), "{} leaked into {}", value, line);
+        }
+        assert!(line.contains("0179b556…[66 chars, fp "));
+        // Equal values keep equal fingerprints, so log lines still correlate
+        assert_eq!(line, log_line(true));
+    }
+
+    #[test]
+    fn values_are_logged_verbatim_when_redaction_is_off() {
+        let line = log_line(false);
+        assert!(line.contains(SIGNER_KEY));
+        assert!(line.contains(SIGNATURE));
+        assert!(line.contains(TRANSACTION));
+    }
+}
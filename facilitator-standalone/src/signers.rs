@@ -0,0 +1,219 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+
+const DEFAULT_SIGNER_PUBKEY: &str =
+    "01234567890abcdef01234567890abcdef01234567890abcdef01234567890abcdef";
+const DEFAULT_SIGN_TIMEOUT_MS: u64 = 5_000;
+
+/// A signing key the facilitator is allowed to use
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignerEntry {
+    pub public_key: String,
+    pub weight: u32,
+}
+
+/// The set of keys the server may sign with.
+///
+/// Requests naming a `signer_key` outside this pool are refused, so a caller
+/// cannot make the facilitator sign with an arbitrary key.
+#[derive(Debug)]
+pub struct SignerPool {
+    signers: Vec<SignerEntry>,
+    selection_counter: AtomicU64,
+    sign_timeout: Duration,
+}
+
+impl SignerPool {
+    pub fn new(signers: Vec<SignerEntry>, sign_timeout: Duration) -> Self {
+        Self {
+            signers,
+            selection_counter: AtomicU64::new(0),
+            sign_timeout,
+        }
+    }
+
+    /// Load the pool from `FACILITATOR_SIGNER_POOL`.
+    ///
+    /// The variable is a comma-separated list of `<public_key_hex>[:<weight>]`
+    /// entries (weight defaults to 1). Entries with a zero weight or an
+    /// unparsable weight are skipped. Without the variable the pool holds the
+    /// built-in demo key.
+    ///
+    /// `FACILITATOR_SIGN_TIMEOUT_MS` bounds how long one key may take to sign
+    /// before the next key is tried.
+    pub fn from_env() -> Self {
+        let signers = match std::env::var("FACILITATOR_SIGNER_POOL") {
+            Ok(value) => parse_signer_pool(&value),
+            Err(_) => vec![SignerEntry {
+                public_key: DEFAULT_SIGNER_PUBKEY.to_string(),
+                weight: 1,
+            }],
+        };
+        let sign_timeout_ms = std::env::var("FACILITATOR_SIGN_TIMEOUT_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|ms| *ms > 0)
+            .unwrap_or(DEFAULT_SIGN_TIMEOUT_MS);
+        Self::new(signers, Duration::from_millis(sign_timeout_ms))
+    }
+
+    pub fn len(&self) -> usize {
+        self.signers.len()
+    }
+
+    /// Look up a signer by public key (hex, case-insensitive)
+    pub fn find(&self, public_key: &str) -> Option<&SignerEntry> {
+        let public_key = public_key.trim().to_ascii_lowercase();
+        self.signers.iter().find(|s| s.public_key == public_key)
+    }
+
+    /// Pick a signer with probability proportional to its weight.
+    ///
+    /// The seed is the Blake2b-256 of a selection counter, so the sequence of
+    /// picks is reproducible and spreads across the pool by weight.
+    pub fn select(&self) -> Option<&SignerEntry> {
+        let counter = self.selection_counter.fetch_add(1, Ordering::Relaxed);
+        let digest = Blake2b::<U32>::digest(counter.to_le_bytes());
+        let mut seed = [0u8; 8];
+        seed.copy_from_slice(&digest[..8]);
+        select_weighted(&self.signers, u64::from_le_bytes(seed))
+    }
+
+    /// Sign with `primary`, falling back to the other signers in descending
+    /// weight order whenever a key does not finish within the sign timeout.
+    ///
+    /// Returns the signer that produced the signature, or `None` if every
+    /// key timed out.
+    pub async fn sign_with_fallback<'a, F, Fut>(
+        &'a self,
+        primary: &'a SignerEntry,
+        sign: F,
+    ) -> Option<(&'a SignerEntry, String)>
+    where
+        F: Fn(&SignerEntry) -> Fut,
+        Fut: Future<Output = String>,
+    {
+        let mut fallbacks: Vec<&SignerEntry> = self
+            .signers
+            .iter()
+            .filter(|s| s.public_key != primary.public_key)
+            .collect();
+        fallbacks.sort_by_key(|s| std::cmp::Reverse(s.weight));
+
+        for signer in std::iter::once(primary).chain(fallbacks) {
+            match tokio::time::timeout(self.sign_timeout, sign(signer)).await {
+                Ok(signature) => return Some((signer, signature)),
+                Err(_) => log::warn!(
+                    "Signer {} did not sign within {:?}; trying the next signer",
+                    crate::redact::sensitive(&signer.public_key),
+                    self.sign_timeout
+                ),
+            }
+        }
+        None
+    }
+}
+
+/// Weighted pick for `seed`, walking the pool the same way as the
+/// contract's `select_weighted_signer`
+fn select_weighted(signers: &[SignerEntry], seed: u64) -> Option<&SignerEntry> {
+    let total_weight: u64 = signers.iter().map(|s| s.weight as u64).sum();
+    if total_weight == 0 {
+        return None;
+    }
+
+    let mut target = seed % total_weight;
+    for signer in signers {
+        let weight = signer.weight as u64;
+        if target < weight {
+            return Some(signer);
+        }
+        target -= weight;
+    }
+    None
+}
+
+fn parse_signer_pool(value: &str) -> Vec<SignerEntry> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| {
+            let (public_key, weight) = match entry.split_once(':') {
+                Some((key, weight)) => (key, weight.trim().parse::<u32>().ok()?),
+                None => (entry, 1),
+            };
+            if weight == 0 {
+                return None;
+            }
+            Some(SignerEntry {
+                public_key: public_key.trim().to_ascii_lowercase(),
+                weight,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(public_key: &str, weight: u32) -> SignerEntry {
+        SignerEntry {
+            public_key: public_key.to_string(),
+            weight,
+        }
+    }
+
+    #[test]
+    fn selection_follows_weights() {
+        let signers = vec![entry("01aa", 1), entry("01bb", 3)];
+        let picks: Vec<&str> = (0..8)
+            .map(|seed| select_weighted(&signers, seed).unwrap().public_key.as_str())
+            .collect();
+        assert_eq!(picks, ["01aa", "01bb", "01bb", "01bb", "01aa", "01bb", "01bb", "01bb"]);
+        assert!(select_weighted(&[], 7).is_none());
+    }
+
+    #[test]
+    fn selection_sequence_is_reproducible() {
+        let signers = vec![entry("01aa", 2), entry("01bb", 5), entry("01cc", 3)];
+        let first = SignerPool::new(signers.clone(), Duration::from_secs(1));
+        let second = SignerPool::new(signers, Duration::from_secs(1));
+        for _ in 0..20 {
+            assert_eq!(first.select(), second.select());
+        }
+    }
+
+    #[tokio::test]
+    async fn slow_primary_falls_back_to_heaviest_other_signer() {
+        let pool = SignerPool::new(
+            vec![entry("01aa", 5), entry("01bb", 1), entry("01cc", 3)],
+            Duration::from_millis(20),
+        );
+        let primary = pool.find("01aa").unwrap();
+
+        let started = std::time::Instant::now();
+        let (signer, signature) = pool
+            .sign_with_fallback(primary, |signer| {
+                let slow = signer.public_key == "01aa";
+                let signature = format!("sig_{}", signer.public_key);
+                async move {
+                    if slow {
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                    }
+                    signature
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(signer.public_key, "01cc");
+        assert_eq!(signature, "sig_01cc");
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+}
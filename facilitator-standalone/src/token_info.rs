@@ -0,0 +1,135 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::node::{NodeClient, NodeError};
+
+const DEFAULT_CACHE_SECS: u64 = 30;
+
+/// Display metadata of a CEP-18 token
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TokenInfo {
+    pub contract_hash: String,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    /// Decimal string; CEP-18 supplies are `U256`
+    pub total_supply: String,
+}
+
+impl TokenInfo {
+    /// Build from the parsed CLValues of the token's named keys
+    pub fn from_named_values(
+        contract_hash: &str,
+        name: &Value,
+        symbol: &Value,
+        decimals: &Value,
+        total_supply: &Value,
+    ) -> Result<Self, NodeError> {
+        let name = name
+            .as_str()
+            .ok_or_else(|| NodeError::InvalidResponse("malformed name".to_string()))?;
+        let symbol = symbol
+            .as_str()
+            .ok_or_else(|| NodeError::InvalidResponse("malformed symbol".to_string()))?;
+        let decimals = decimals
+            .as_u64()
+            .and_then(|d| u8::try_from(d).ok())
+            .ok_or_else(|| NodeError::InvalidResponse("malformed decimals".to_string()))?;
+        let total_supply = match total_supply {
+            Value::String(s) if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) => s.clone(),
+            Value::Number(n) if n.is_u64() => n.to_string(),
+            _ => return Err(NodeError::InvalidResponse("malformed total_supply".to_string())),
+        };
+
+        Ok(Self {
+            contract_hash: contract_hash.to_string(),
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            decimals,
+            total_supply,
+        })
+    }
+
+    /// Read the token's `name`, `symbol`, `decimals` and `total_supply`
+    pub async fn fetch(node: &NodeClient, contract_hash: &str) -> Result<Self, NodeError> {
+        let name = node.get_contract_named_value(contract_hash, "name").await?;
+        let symbol = node.get_contract_named_value(contract_hash, "symbol").await?;
+        let decimals = node.get_contract_named_value(contract_hash, "decimals").await?;
+        let total_supply = node.get_contract_named_value(contract_hash, "total_supply").await?;
+        Self::from_named_values(contract_hash, &name, &symbol, &decimals, &total_supply)
+    }
+}
+
+/// Briefly caches the token metadata so UI polling doesn't hit the node
+#[derive(Debug)]
+pub struct TokenInfoCache {
+    ttl: Duration,
+    entry: Mutex<Option<(Instant, TokenInfo)>>,
+}
+
+impl TokenInfoCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entry: Mutex::new(None),
+        }
+    }
+
+    /// TTL from `FACILITATOR_TOKEN_INFO_CACHE_SECS` (0 disables caching)
+    pub fn from_env() -> Self {
+        let ttl_secs = std::env::var("FACILITATOR_TOKEN_INFO_CACHE_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_CACHE_SECS);
+        Self::new(Duration::from_secs(ttl_secs))
+    }
+
+    /// Cached metadata for `contract_hash`, fetched from the node when
+    /// missing, stale, or cached for a different contract
+    pub async fn get(&self, node: &NodeClient, contract_hash: &str) -> Result<TokenInfo, NodeError> {
+        if let Some((fetched_at, info)) = self.entry.lock().unwrap().as_ref() {
+            if info.contract_hash == contract_hash && fetched_at.elapsed() < self.ttl {
+                return Ok(info.clone());
+            }
+        }
+
+        let info = TokenInfo::fetch(node, contract_hash).await?;
+        *self.entry.lock().unwrap() = Some((Instant::now(), info.clone()));
+        Ok(info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn builds_token_info_from_named_values() {
+        let info = TokenInfo::from_named_values(
+            "6a54",
+            &json!("USD Coin"),
+            &json!("USDC"),
+            &json!(6),
+            &json!("1000000000000000000000000000000"),
+        )
+        .unwrap();
+
+        assert_eq!(info.name, "USD Coin");
+        assert_eq!(info.symbol, "USDC");
+        assert_eq!(info.decimals, 6);
+        assert_eq!(info.total_supply, "1000000000000000000000000000000");
+    }
+
+    #[test]
+    fn rejects_malformed_named_values() {
+        assert!(TokenInfo::from_named_values("6a54", &json!("USD Coin"), &json!("USDC"), &json!(300), &json!("1"))
+            .is_err());
+        assert!(TokenInfo::from_named_values("6a54", &json!("USD Coin"), &json!("USDC"), &json!(6), &json!("-1"))
+            .is_err());
+        assert!(TokenInfo::from_named_values("6a54", &json!(null), &json!("USDC"), &json!(6), &json!("1")).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt;
+
+/// Decimals of native CSPR (1 CSPR = 10^9 motes)
+pub const CSPR_DECIMALS: u8 = 9;
+
+/// `U256::MAX`, the largest CEP-18 amount, in decimal
+pub const U256_MAX_DECIMAL: &str =
+    "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AmountParseError {
+    Empty,
+    NotNumeric,
+    TooLarge,
+}
+
+impl fmt::Display for AmountParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AmountParseError::Empty => write!(f, "amount is empty"),
+            AmountParseError::NotNumeric => write!(f, "amount must be a non-negative decimal integer"),
+            AmountParseError::TooLarge => write!(f, "amount exceeds U256::MAX"),
+        }
+    }
+}
+
+/// Validate a base-unit amount destined for a `U256` runtime arg and return
+/// it in canonical form (no leading zeros), which is how the contract
+/// formats the amount when it rebuilds the signed message
+pub fn parse_u256_amount(value: &str) -> Result<String, AmountParseError> {
+    if value.is_empty() {
+        return Err(AmountParseError::Empty);
+    }
+    if !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(AmountParseError::NotNumeric);
+    }
+
+    let digits = value.trim_start_matches('0');
+    let digits = if digits.is_empty() { "0" } else { digits };
+    // Equal-length decimal strings compare like the numbers they spell
+    if digits.len() > U256_MAX_DECIMAL.len()
+        || (digits.len() == U256_MAX_DECIMAL.len() && digits > U256_MAX_DECIMAL)
+    {
+        return Err(AmountParseError::TooLarge);
+    }
+    Ok(digits.to_string())
+}
+
+/// Order two amounts in the canonical form `parse_u256_amount` returns
+pub fn cmp_amounts(a: &str, b: &str) -> std::cmp::Ordering {
+    // Without leading zeros, the longer number is the larger one
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Format a base-unit amount as a human-readable token amount.
+///
+/// Trailing fractional zeros are trimmed down to two places, e.g.
+/// `format_token_amount(1_500_000, 6, "USDC")` is `"1.50 USDC"`. Works on the
+/// decimal string, so any `decimals` value is handled without overflow.
+pub fn format_token_amount(amount: u128, decimals: u8, symbol: &str) -> String {
+    let decimals = decimals as usize;
+    let digits = amount.to_string();
+
+    if decimals == 0 {
+        return format!("{} {}", digits, symbol);
+    }
+
+    let padded = format!("{:0>width$}", digits, width = decimals + 1);
+    let (whole, fraction) = padded.split_at(padded.len() - decimals);
+
+    let min_fraction = decimals.min(2);
+    let trimmed = fraction.trim_end_matches('0');
+    let fraction = if trimmed.len() < min_fraction {
+        &fraction[..min_fraction]
+    } else {
+        trimmed
+    };
+
+    format!("{}.{} {}", whole, fraction, symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_valid_amounts_canonically() {
+        assert_eq!(parse_u256_amount("1000000"), Ok("1000000".to_string()));
+        assert_eq!(parse_u256_amount("000"), Ok("0".to_string()));
+        assert_eq!(parse_u256_amount("0042"), Ok("42".to_string()));
+        assert_eq!(parse_u256_amount(U256_MAX_DECIMAL), Ok(U256_MAX_DECIMAL.to_string()));
+    }
+
+    #[test]
+    fn rejects_non_numeric_amounts() {
+        assert_eq!(parse_u256_amount(""), Err(AmountParseError::Empty));
+        assert_eq!(parse_u256_amount("-5"), Err(AmountParseError::NotNumeric));
+        assert_eq!(parse_u256_amount("1.5"), Err(AmountParseError::NotNumeric));
+        assert_eq!(parse_u256_amount(" 10"), Err(AmountParseError::NotNumeric));
+        assert_eq!(parse_u256_amount("0x10"), Err(AmountParseError::NotNumeric));
+    }
+
+    #[test]
+    fn rejects_amounts_over_u256_max() {
+        let just_over = "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+        assert_eq!(parse_u256_amount(just_over), Err(AmountParseError::TooLarge));
+        assert_eq!(parse_u256_amount(&format!("1{}", U256_MAX_DECIMAL)), Err(AmountParseError::TooLarge));
+        assert_eq!(
+            parse_u256_amount(&format!("000{}", U256_MAX_DECIMAL)),
+            Ok(U256_MAX_DECIMAL.to_string())
+        );
+    }
+
+    #[test]
+    fn formats_tokens_without_decimals() {
+        assert_eq!(format_token_amount(0, 0, "PTS"), "0 PTS");
+        assert_eq!(format_token_amount(1_500, 0, "PTS"), "1500 PTS");
+    }
+
+    #[test]
+    fn trims_fractions_down_to_two_places() {
+        assert_eq!(format_token_amount(1_500_000, 6, "USDC"), "1.50 USDC");
+        assert_eq!(format_token_amount(1_000_000, 6, "USDC"), "1.00 USDC");
+        assert_eq!(format_token_amount(1_234_500, 6, "USDC"), "1.2345 USDC");
+        assert_eq!(format_token_amount(2_500_000_000, CSPR_DECIMALS, "CSPR"), "2.50 CSPR");
+        // A single decimal place is never padded to two
+        assert_eq!(format_token_amount(15, 1, "TKN"), "1.5 TKN");
+    }
+
+    #[test]
+    fn formats_amounts_below_one_unit() {
+        assert_eq!(format_token_amount(0, 6, "USDC"), "0.00 USDC");
+        assert_eq!(format_token_amount(1, 6, "USDC"), "0.000001 USDC");
+        assert_eq!(format_token_amount(50_000, 6, "USDC"), "0.05 USDC");
+    }
+
+    #[test]
+    fn formats_large_decimals() {
+        assert_eq!(format_token_amount(1_500_000_000_000_000_000, 18, "ETH"), "1.50 ETH");
+        assert_eq!(format_token_amount(1, 18, "ETH"), "0.000000000000000001 ETH");
+        assert_eq!(
+            format_token_amount(u128::MAX, 40, "BIG"),
+            "0.0340282366920938463463374607431768211455 BIG"
+        );
+        assert_eq!(format_token_amount(7, u8::MAX, "MAX").len(), "0.".len() + 255 + " MAX".len());
+    }
+}
@@ -0,0 +1,313 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use warp::http::StatusCode;
+use warp::hyper::body::Bytes;
+use warp::{Filter, Rejection};
+
+use crate::{
+    hashes, units, ClaimMessageRequest, EstimateFeeRequest, PaymentVerificationRequest,
+    SignTransactionRequest,
+};
+
+/// One problem with a request body
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldError {
+    /// Offending field (`body` when the JSON itself is unusable), with an
+    /// `[index].` prefix for batch entries
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Checks on a request beyond what its type already enforces
+pub trait Validate {
+    /// Every problem found, empty when the request is usable
+    fn validate(&self) -> Vec<FieldError>;
+}
+
+/// Rejection carrying the field errors of an unusable request body
+#[derive(Debug)]
+pub struct InvalidBody(pub Vec<FieldError>);
+
+impl warp::reject::Reject for InvalidBody {}
+
+/// Deserialize and validate a JSON request body
+pub fn parse_body<T: DeserializeOwned + Validate>(body: &[u8]) -> Result<T, Vec<FieldError>> {
+    let deserializer = &mut serde_json::Deserializer::from_slice(body);
+    let request: T = serde_path_to_error::deserialize(deserializer).map_err(|e| vec![deserialize_error(e)])?;
+    let errors = request.validate();
+    if errors.is_empty() {
+        Ok(request)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Extract a validated JSON body, rejecting with [`InvalidBody`] otherwise
+pub fn json_body<T>() -> impl Filter<Extract = (T,), Error = Rejection> + Clone
+where
+    T: DeserializeOwned + Validate + Send,
+{
+    warp::body::bytes().and_then(|body: Bytes| async move {
+        parse_body::<T>(&body).map_err(|errors| warp::reject::custom(InvalidBody(errors)))
+    })
+}
+
+/// Answer [`InvalidBody`] rejections with a 400 listing the field errors;
+/// other rejections are passed on
+pub async fn recover_invalid_body(rejection: Rejection) -> Result<warp::reply::Response, Rejection> {
+    match rejection.find::<InvalidBody>() {
+        Some(InvalidBody(errors)) => Ok(invalid_body_reply(errors)),
+        None => Err(rejection),
+    }
+}
+
+pub fn invalid_body_reply(errors: &[FieldError]) -> warp::reply::Response {
+    use warp::Reply;
+
+    warp::reply::with_status(
+        warp::reply::json(&serde_json::json!({
+            "error": "Invalid request body",
+            "field_errors": errors,
+        })),
+        StatusCode::BAD_REQUEST,
+    )
+    .into_response()
+}
+
+/// Turn a serde error into a field error, naming the field where serde does
+fn deserialize_error(error: serde_path_to_error::Error<serde_json::Error>) -> FieldError {
+    let path = error.path().to_string();
+    let inner = error.into_inner();
+    let message = inner.to_string();
+    // Positions are meaningless to clients once the field is named
+    let message = match message.rsplit_once(" at line ") {
+        Some((message, _)) => message.to_string(),
+        None => message,
+    };
+
+    if inner.is_syntax() || inner.is_eof() {
+        return FieldError::new("body", format!("is not valid JSON: {}", message));
+    }
+    // The path of a missing field stops at its parent; an unknown field's
+    // path already ends with the field itself
+    if let Some((field, _)) = message.strip_prefix("missing field `").and_then(|rest| rest.split_once('`')) {
+        return FieldError::new(&join_path(&path, field), "is required");
+    }
+    if message.starts_with("unknown field `") {
+        return FieldError::new(&path, "is not allowed");
+    }
+    let field = if path == "." { "body" } else { path.as_str() };
+    FieldError::new(field, message)
+}
+
+fn join_path(parent: &str, field: &str) -> String {
+    if parent == "." {
+        field.to_string()
+    } else {
+        format!("{}.{}", parent, field)
+    }
+}
+
+fn require_non_empty(errors: &mut Vec<FieldError>, field: &str, value: &str) {
+    if value.trim().is_empty() {
+        errors.push(FieldError::new(field, "must not be empty"));
+    }
+}
+
+fn check_account_hash(errors: &mut Vec<FieldError>, field: &str, value: Option<&str>) {
+    if let Some(Err(e)) = value.map(hashes::parse_account_hash) {
+        errors.push(FieldError::new(field, e.to_string()));
+    }
+}
+
+impl Validate for SignTransactionRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        require_non_empty(&mut errors, "transaction", &self.transaction);
+        if let Some(signer_key) = &self.signer_key {
+            if hashes::account_hash_from_public_key(signer_key.trim()).is_none() {
+                errors.push(FieldError::new(
+                    "signer_key",
+                    "must be a hex ed25519 (01) or secp256k1 (02) public key",
+                ));
+            }
+        }
+        if self.gas_payment_motes == Some(0) {
+            errors.push(FieldError::new("gas_payment_motes", "must be greater than 0"));
+        }
+        check_account_hash(&mut errors, "payer", self.payer.as_deref());
+        errors
+    }
+}
+
+impl Validate for EstimateFeeRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        if self.transaction_size == Some(0) {
+            errors.push(FieldError::new("transaction_size", "must be greater than 0"));
+        }
+        if self.batch_size == Some(0) {
+            errors.push(FieldError::new("batch_size", "must be greater than 0"));
+        }
+        if let Some(Err(e)) = self.fee_token.as_deref().map(hashes::parse_contract_hash) {
+            errors.push(FieldError::new("fee_token", e.to_string()));
+        }
+        check_account_hash(&mut errors, "payer", self.payer.as_deref());
+        errors
+    }
+}
+
+/// Only the fields a payment cannot be looked up without are checked here;
+/// the rest are judged per payment, so one bad entry does not fail a batch
+impl Validate for PaymentVerificationRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        require_non_empty(&mut errors, "deploy_hash", &self.deploy_hash);
+        errors
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate(&self) -> Vec<FieldError> {
+        self.iter()
+            .enumerate()
+            .flat_map(|(i, entry)| {
+                entry.validate().into_iter().map(move |error| FieldError {
+                    field: format!("[{}].{}", i, error.field),
+                    message: error.message,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Validate for ClaimMessageRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        check_account_hash(&mut errors, "recipient", Some(&self.recipient));
+        if let Err(e) = units::parse_u256_amount(&self.amount) {
+            errors.push(FieldError::new("amount", e.to_string()));
+        }
+        check_account_hash(&mut errors, "payer", self.payer.as_deref());
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCOUNT: &str = "account-hash-0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    /// Send `body` through the validating filter the way an endpoint does
+    async fn submit<T>(body: &str) -> (StatusCode, serde_json::Value)
+    where
+        T: DeserializeOwned + Validate + Send + 'static,
+    {
+        let filter = json_body::<T>()
+            .map(|_| warp::reply())
+            .recover(recover_invalid_body);
+        let response = warp::test::request()
+            .method("POST")
+            .body(body)
+            .reply(&filter)
+            .await;
+        let status = response.status();
+        let body = serde_json::from_slice(response.body()).unwrap_or(serde_json::Value::Null);
+        (status, body)
+    }
+
+    fn fields(body: &serde_json::Value) -> Vec<(String, String)> {
+        body["field_errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| (e["field"].as_str().unwrap().to_string(), e["message"].as_str().unwrap().to_string()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn sign_and_send_bodies_report_field_errors() {
+        let (status, body) = submit::<SignTransactionRequest>(r#"{"signer_key": "01aa"}"#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(fields(&body), vec![("transaction".to_string(), "is required".to_string())]);
+
+        let (status, body) = submit::<SignTransactionRequest>(
+            r#"{"transaction": "", "signer_key": "01aa", "gas_payment_motes": 0, "payer": "0123"}"#,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let fields: Vec<String> = fields(&body).into_iter().map(|(field, _)| field).collect();
+        assert_eq!(fields, vec!["transaction", "signer_key", "gas_payment_motes", "payer"]);
+
+        let (status, _) = submit::<SignTransactionRequest>(r#"{"transaction": "0a0b"}"#).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn unknown_fields_and_wrong_types_are_rejected() {
+        let (status, body) =
+            submit::<EstimateFeeRequest>(r#"{"transaction_size": 100, "congestion": 3}"#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(fields(&body), vec![("congestion".to_string(), "is not allowed".to_string())]);
+
+        let (status, body) = submit::<EstimateFeeRequest>(r#"{"transaction_size": "big"}"#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(fields(&body)[0].0, "transaction_size");
+
+        let (status, body) = submit::<EstimateFeeRequest>(r#"{"batch_size": 0, "fee_token": "6a54"}"#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let fields: Vec<String> = fields(&body).into_iter().map(|(field, _)| field).collect();
+        assert_eq!(fields, vec!["batch_size", "fee_token"]);
+
+        let (status, body) = submit::<EstimateFeeRequest>("{not json").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["field_errors"][0]["field"], "body");
+    }
+
+    #[tokio::test]
+    async fn verification_bodies_report_field_errors() {
+        let (status, body) =
+            submit::<PaymentVerificationRequest>(r#"{"deploy_hash": "", "amount": "1"}"#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(fields(&body), vec![("recipient".to_string(), "is required".to_string())]);
+
+        let batch = format!(
+            r#"[{{"deploy_hash": "d1", "amount": "1", "recipient": "{0}"}},
+                {{"deploy_hash": " ", "amount": "1", "recipient": "{0}"}}]"#,
+            ACCOUNT
+        );
+        let (status, body) = submit::<Vec<PaymentVerificationRequest>>(&batch).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(fields(&body), vec![("[1].deploy_hash".to_string(), "must not be empty".to_string())]);
+
+        let batch = format!(r#"[{{"deploy_hash": "d1", "amount": 1, "recipient": "{}"}}]"#, ACCOUNT);
+        let (status, body) = submit::<Vec<PaymentVerificationRequest>>(&batch).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(fields(&body)[0].0, "[0].amount");
+    }
+
+    #[tokio::test]
+    async fn claim_message_bodies_report_field_errors() {
+        let (status, body) = submit::<ClaimMessageRequest>(
+            r#"{"recipient": "account-hash-zz", "amount": "-5", "payer": "nope"}"#,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let fields: Vec<String> = fields(&body).into_iter().map(|(field, _)| field).collect();
+        assert_eq!(fields, vec!["recipient", "amount", "payer"]);
+
+        let valid = format!(r#"{{"recipient": "{}", "amount": "1000", "nonce": 0}}"#, ACCOUNT);
+        let (status, _) = submit::<ClaimMessageRequest>(&valid).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
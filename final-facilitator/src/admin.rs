@@ -1,9 +1,9 @@
 use alloc::{vec::Vec, string::String};
-use casper_types::{account::AccountHash, ContractHash};
+use casper_types::{account::AccountHash, system::CallStackElement, ContractHash};
 
 use crate::constants::*;
 use crate::errors::*;
-use crate::types::TokenAccountInfo;
+use crate::types::{AdminActionRecord, SignerInfo, TokenAccountInfo};
 use crate::storage::*;
 
 /// Initialize token accounts for all allowed payment tokens for the paymaster
@@ -55,7 +55,7 @@ pub fn initialize_atas(
 /// Initialize token accounts for all allowed payment tokens for the provided addresses with configurable chunk size
 /// This function does not use cache and directly checks on-chain
 pub fn initialize_atas_with_chunk_size(
-    addresses_to_initialize_atas: &Vec<AccountHash>,
+    addresses_to_initialize_atas: &[AccountHash],
     _compute_unit_price: Option<u64>,
     _compute_unit_limit: Option<u32>,
     chunk_size: usize,
@@ -192,12 +192,105 @@ pub fn is_token_supported(token_contract: &ContractHash) -> bool {
     supported_tokens.contains(token_contract)
 }
 
+/// Insert `token_contract` into the sorted `supported_tokens` registry
+///
+/// Fails with `InvalidArgument` when the token is already supported and
+/// with `TokenLimitExceeded` when the registry is full.
+pub fn insert_supported_token(
+    supported_tokens: &mut Vec<ContractHash>,
+    token_contract: ContractHash,
+) -> Result<(), casper_types::ApiError> {
+    let pos = match supported_tokens.binary_search(&token_contract) {
+        Ok(_) => return Err(casper_types::ApiError::InvalidArgument),
+        Err(pos) => pos,
+    };
+    if supported_tokens.len() >= MAX_SUPPORTED_TOKENS {
+        return Err(token_limit_exceeded_error());
+    }
+    supported_tokens.insert(pos, token_contract);
+    Ok(())
+}
+
+/// Insert every token in `tokens` into the sorted `supported_tokens`
+/// registry, or none of them
+///
+/// Fails with `InvalidArgument` for an empty batch, a token that is already
+/// supported or one that appears twice in the batch, and with
+/// `TokenLimitExceeded` when the batch does not fit in the registry.
+pub fn insert_supported_tokens(
+    supported_tokens: &mut Vec<ContractHash>,
+    tokens: &[ContractHash],
+) -> Result<(), casper_types::ApiError> {
+    if tokens.is_empty() {
+        return Err(casper_types::ApiError::InvalidArgument);
+    }
+    if supported_tokens.len().saturating_add(tokens.len()) > MAX_SUPPORTED_TOKENS {
+        return Err(token_limit_exceeded_error());
+    }
+    
+    let mut updated = supported_tokens.clone();
+    for token_contract in tokens {
+        insert_supported_token(&mut updated, *token_contract)?;
+    }
+    *supported_tokens = updated;
+    Ok(())
+}
+
+/// Remove `token_contract` from the sorted `supported_tokens` registry,
+/// returning whether it was there
+pub fn remove_supported_token(supported_tokens: &mut Vec<ContractHash>, token_contract: ContractHash) -> bool {
+    match supported_tokens.binary_search(&token_contract) {
+        Ok(pos) => {
+            supported_tokens.remove(pos);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// Append `record` to the audit log, dropping the oldest entries beyond
+/// `MAX_ADMIN_ACTIONS`, and return how many were dropped
+pub fn push_admin_action(admin_actions: &mut Vec<AdminActionRecord>, record: AdminActionRecord) -> usize {
+    let excess = (admin_actions.len() + 1).saturating_sub(MAX_ADMIN_ACTIONS);
+    admin_actions.drain(..excess);
+    admin_actions.push(record);
+    excess
+}
+
+/// The last `count` entries of the audit log, oldest first
+pub fn recent_admin_actions(mut admin_actions: Vec<AdminActionRecord>, count: u32) -> Vec<AdminActionRecord> {
+    let skip = admin_actions.len().saturating_sub(count as usize);
+    admin_actions.drain(..skip);
+    admin_actions
+}
+
+/// Hash of the stored contract on top of `call_stack`, if the top frame
+/// is one
+pub fn executing_contract_hash(call_stack: &[CallStackElement]) -> Option<ContractHash> {
+    match call_stack.last()? {
+        CallStackElement::StoredContract { contract_hash, .. } => Some(*contract_hash),
+        _ => None,
+    }
+}
+
 /// Get the number of active signers in the pool
 pub fn get_active_signer_count() -> u32 {
     let signer_pool = get_signer_pool();
     signer_pool.iter().filter(|s| s.is_active).count() as u32
 }
 
+/// Check that `signer_pool` has at least `min_active_signers` active signers
+pub fn check_active_signers(
+    signer_pool: &[SignerInfo],
+    min_active_signers: u32,
+) -> Result<(), casper_types::ApiError> {
+    let active = signer_pool.iter().filter(|s| s.is_active).count();
+    if active < min_active_signers as usize {
+        return Err(insufficient_signers_error());
+    }
+    Ok(())
+}
+
 /// Calculate the total weight of active signers
 pub fn get_total_active_signer_weight() -> u64 {
     let signer_pool = get_signer_pool();
@@ -208,6 +301,130 @@ pub fn get_total_active_signer_weight() -> u64 {
         .sum()
 }
 
+/// Check that a signer's weight is between 1 and `max_signer_weight`
+///
+/// A zero-weight signer could never be selected yet would still count
+/// towards the active-signer minimum.
+pub fn validate_signer_weight(weight: u32, max_signer_weight: u32) -> Result<(), casper_types::ApiError> {
+    if weight == 0 || weight > max_signer_weight {
+        return Err(casper_types::ApiError::InvalidArgument);
+    }
+    Ok(())
+}
+
+/// The account that becomes admin when `caller` accepts the handoff.
+///
+/// Fails with `InvalidArgument` when no handoff is pending and with
+/// `PermissionDenied` when `caller` is not the proposed account.
+pub fn check_admin_acceptance(
+    pending_admin: Option<AccountHash>,
+    caller: AccountHash,
+) -> Result<AccountHash, casper_types::ApiError> {
+    let pending_admin = pending_admin.ok_or(casper_types::ApiError::InvalidArgument)?;
+    if caller != pending_admin {
+        return Err(casper_types::ApiError::PermissionDenied);
+    }
+    Ok(pending_admin)
+}
+
+/// Pick an active signer with probability proportional to its weight.
+///
+/// `seed` is reduced modulo the total active weight and walked through the
+/// pool in order, so the same seed and pool always pick the same signer.
+/// Returns `None` when no active signer has any weight.
+pub fn select_weighted_signer(signer_pool: &[SignerInfo], seed: u64) -> Option<&SignerInfo> {
+    let total_weight: u64 = signer_pool
+        .iter()
+        .filter(|s| s.is_active)
+        .map(|s| s.weight as u64)
+        .sum();
+    if total_weight == 0 {
+        return None;
+    }
+    
+    let mut target = seed % total_weight;
+    for signer in signer_pool.iter().filter(|s| s.is_active) {
+        if target < signer.weight as u64 {
+            return Some(signer);
+        }
+        target -= signer.weight as u64;
+    }
+    None
+}
+
+/// Mark the signer `account_hash` active or inactive, keeping it and its
+/// weight in the pool
+pub fn set_signer_active(
+    signer_pool: &mut [SignerInfo],
+    account_hash: AccountHash,
+    is_active: bool,
+) -> Result<(), casper_types::ApiError> {
+    let signer = signer_pool
+        .iter_mut()
+        .find(|s| s.account_hash == account_hash)
+        .ok_or(casper_types::ApiError::InvalidArgument)?;
+    signer.is_active = is_active;
+    Ok(())
+}
+
+/// Remove `account_hash` from `signer_pool` and split its weight across
+/// `targets`, returning the weight that was moved.
+///
+/// The weight is split evenly; the remainder goes one unit at a time to the
+/// targets in the order given. Every target must be a distinct, active
+/// signer other than the one being removed, so the total pool weight is
+/// unchanged, and no target may end up above `max_signer_weight`. The pool
+/// is left untouched when the reassignment is rejected.
+pub fn reassign_signer_weight(
+    signer_pool: &mut Vec<SignerInfo>,
+    account_hash: AccountHash,
+    targets: &[AccountHash],
+    max_signer_weight: u32,
+) -> Result<u32, casper_types::ApiError> {
+    let pos = signer_pool
+        .iter()
+        .position(|s| s.account_hash == account_hash)
+        .ok_or(casper_types::ApiError::InvalidArgument)?;
+    
+    if targets.is_empty() || targets.contains(&account_hash) {
+        return Err(casper_types::ApiError::InvalidArgument);
+    }
+    for (i, target) in targets.iter().enumerate() {
+        if targets[..i].contains(target) {
+            return Err(casper_types::ApiError::InvalidArgument);
+        }
+        if !signer_pool.iter().any(|s| s.account_hash == *target && s.is_active) {
+            return Err(casper_types::ApiError::InvalidArgument);
+        }
+    }
+    
+    let weight = signer_pool[pos].weight;
+    let share = weight / targets.len() as u32;
+    let remainder = weight % targets.len() as u32;
+    let mut new_weights = Vec::with_capacity(targets.len());
+    for (i, target) in targets.iter().enumerate() {
+        let extra = if (i as u32) < remainder { 1 } else { 0 };
+        let current = signer_pool
+            .iter()
+            .find(|s| s.account_hash == *target)
+            .ok_or(casper_types::ApiError::InvalidArgument)?
+            .weight;
+        let new_weight = current
+            .checked_add(share + extra)
+            .ok_or(casper_types::ApiError::InvalidArgument)?;
+        validate_signer_weight(new_weight, max_signer_weight)?;
+        new_weights.push(new_weight);
+    }
+    for (target, new_weight) in targets.iter().zip(new_weights) {
+        if let Some(signer) = signer_pool.iter_mut().find(|s| s.account_hash == *target) {
+            signer.weight = new_weight;
+        }
+    }
+    signer_pool.remove(pos);
+    
+    Ok(weight)
+}
+
 /// Validate chunk size parameter
 pub fn validate_chunk_size(chunk_size: usize) -> Result<(), casper_types::ApiError> {
     if chunk_size == 0 || chunk_size > MAX_CHUNK_SIZE {
@@ -225,4 +442,284 @@ pub fn estimate_initialization_cost(
     let total_accounts = address_count as u64 * token_count as u64;
     
     total_accounts.saturating_mul(base_cost_per_account)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use casper_types::{ContractPackageHash, PublicKey, SecretKey};
+
+    fn signer(seed: u8, weight: u32, is_active: bool) -> SignerInfo {
+        let public_key = PublicKey::from(&SecretKey::ed25519_from_bytes([seed; 32]).unwrap());
+        SignerInfo {
+            account_hash: AccountHash::from(&public_key),
+            public_key,
+            weight,
+            is_active,
+        }
+    }
+
+    fn total_weight(signer_pool: &[SignerInfo]) -> u64 {
+        signer_pool.iter().map(|s| s.weight as u64).sum()
+    }
+
+    #[test]
+    fn contract_hash_comes_from_the_top_stored_contract_frame() {
+        let session = CallStackElement::Session { account_hash: AccountHash::new([1u8; 32]) };
+        let contract = CallStackElement::StoredContract {
+            contract_package_hash: ContractPackageHash::new([2u8; 32]),
+            contract_hash: ContractHash::new([3u8; 32]),
+        };
+
+        assert_eq!(executing_contract_hash(&[session.clone(), contract.clone()]), Some(ContractHash::new([3u8; 32])));
+        // Called directly from session code rather than through the contract
+        assert_eq!(executing_contract_hash(&[contract, session]), None);
+        assert_eq!(executing_contract_hash(&[]), None);
+    }
+
+    #[test]
+    fn supported_token_order_ignores_insertion_order() {
+        let tokens = [ContractHash::new([3u8; 32]), ContractHash::new([1u8; 32]), ContractHash::new([2u8; 32])];
+        let mut forward = Vec::new();
+        let mut backward = Vec::new();
+        for token in tokens {
+            insert_supported_token(&mut forward, token).unwrap();
+        }
+        for token in tokens.iter().rev() {
+            insert_supported_token(&mut backward, *token).unwrap();
+        }
+        assert_eq!(forward, backward);
+        assert!(forward.windows(2).all(|pair| pair[0] < pair[1]));
+
+        assert_eq!(insert_supported_token(&mut forward, tokens[0]), Err(casper_types::ApiError::InvalidArgument));
+        assert!(remove_supported_token(&mut forward, tokens[2]));
+        assert!(!remove_supported_token(&mut forward, tokens[2]));
+        assert_eq!(forward, vec![tokens[1], tokens[0]]);
+    }
+
+    #[test]
+    fn supported_token_registry_is_capped() {
+        let mut supported_tokens: Vec<ContractHash> =
+            (0..MAX_SUPPORTED_TOKENS as u8).map(|i| ContractHash::new([i; 32])).collect();
+        assert_eq!(
+            insert_supported_token(&mut supported_tokens, ContractHash::new([u8::MAX; 32])),
+            Err(token_limit_exceeded_error())
+        );
+        assert_eq!(supported_tokens.len(), MAX_SUPPORTED_TOKENS);
+    }
+
+    #[test]
+    fn token_batches_are_added_all_or_nothing() {
+        let token = |seed: u8| ContractHash::new([seed; 32]);
+        let mut supported_tokens = vec![token(5)];
+
+        for batch in [vec![], vec![token(1), token(5)], vec![token(2), token(2)]] {
+            assert_eq!(
+                insert_supported_tokens(&mut supported_tokens, &batch),
+                Err(casper_types::ApiError::InvalidArgument)
+            );
+            assert_eq!(supported_tokens, vec![token(5)]);
+        }
+
+        let too_many: Vec<ContractHash> = (10..10 + MAX_SUPPORTED_TOKENS as u8).map(token).collect();
+        assert_eq!(insert_supported_tokens(&mut supported_tokens, &too_many), Err(token_limit_exceeded_error()));
+        assert_eq!(supported_tokens, vec![token(5)]);
+
+        assert_eq!(insert_supported_tokens(&mut supported_tokens, &[token(9), token(1)]), Ok(()));
+        assert_eq!(supported_tokens, vec![token(1), token(5), token(9)]);
+    }
+
+    #[test]
+    fn admin_log_keeps_the_most_recent_actions() {
+        use crate::types::AdminAction;
+
+        let actor = signer(1, 1, true).account_hash;
+        let record = |blocktime: u64| AdminActionRecord {
+            action: AdminAction::TokenAdded,
+            actor,
+            blocktime,
+        };
+        let blocktimes = |log: &[AdminActionRecord]| log.iter().map(|r| r.blocktime).collect::<Vec<_>>();
+
+        let mut admin_actions = Vec::new();
+        for blocktime in 0..MAX_ADMIN_ACTIONS as u64 {
+            assert_eq!(push_admin_action(&mut admin_actions, record(blocktime)), 0);
+        }
+        assert_eq!(push_admin_action(&mut admin_actions, record(1_000)), 1);
+        assert_eq!(admin_actions.len(), MAX_ADMIN_ACTIONS);
+        assert_eq!(admin_actions[0].blocktime, 1);
+
+        let recent = recent_admin_actions(admin_actions.clone(), 3);
+        assert_eq!(blocktimes(&recent), vec![MAX_ADMIN_ACTIONS as u64 - 2, MAX_ADMIN_ACTIONS as u64 - 1, 1_000]);
+        assert_eq!(recent_admin_actions(admin_actions, 0).len(), 0);
+        assert_eq!(recent_admin_actions(vec![record(7)], 10).len(), 1);
+    }
+
+    #[test]
+    fn reassigning_weight_preserves_total() {
+        let mut signer_pool = vec![signer(1, 10, true), signer(2, 3, true), signer(3, 5, true)];
+        let removed = signer_pool[0].account_hash;
+        let targets = [signer_pool[2].account_hash, signer_pool[1].account_hash];
+        let before = total_weight(&signer_pool);
+
+        assert_eq!(reassign_signer_weight(&mut signer_pool, removed, &targets, DEFAULT_MAX_SIGNER_WEIGHT), Ok(10));
+
+        assert_eq!(total_weight(&signer_pool), before);
+        assert_eq!(signer_pool.len(), 2);
+        assert_eq!(signer_pool[0].weight, 8);
+        assert_eq!(signer_pool[1].weight, 10);
+    }
+
+    #[test]
+    fn payments_need_minimum_active_signers() {
+        let mut signer_pool = vec![signer(1, 1, true), signer(2, 1, true), signer(3, 1, false)];
+        assert_eq!(check_active_signers(&signer_pool, 2), Ok(()));
+
+        signer_pool.remove(0);
+        assert_eq!(check_active_signers(&signer_pool, 2), Err(insufficient_signers_error()));
+        assert_eq!(check_active_signers(&signer_pool, 1), Ok(()));
+
+        signer_pool.remove(0);
+        assert_eq!(check_active_signers(&signer_pool, 1), Err(insufficient_signers_error()));
+        assert_eq!(check_active_signers(&signer_pool, 0), Ok(()));
+    }
+
+    #[test]
+    fn reassigning_to_invalid_targets_is_rejected() {
+        let mut signer_pool = vec![signer(1, 10, true), signer(2, 3, false), signer(3, 5, true)];
+        let removed = signer_pool[0].account_hash;
+        let inactive = signer_pool[1].account_hash;
+        let active = signer_pool[2].account_hash;
+        let unknown = signer(4, 1, true).account_hash;
+
+        for targets in [vec![], vec![inactive], vec![unknown], vec![active, active], vec![removed]] {
+            assert!(reassign_signer_weight(&mut signer_pool, removed, &targets, DEFAULT_MAX_SIGNER_WEIGHT).is_err());
+        }
+        assert_eq!(signer_pool.len(), 3);
+        assert_eq!(total_weight(&signer_pool), 18);
+    }
+
+    #[test]
+    fn signer_weight_must_be_within_bounds() {
+        assert_eq!(validate_signer_weight(0, 50), Err(casper_types::ApiError::InvalidArgument));
+        assert_eq!(validate_signer_weight(1, 50), Ok(()));
+        assert_eq!(validate_signer_weight(50, 50), Ok(()));
+        assert_eq!(validate_signer_weight(51, 50), Err(casper_types::ApiError::InvalidArgument));
+    }
+
+    #[test]
+    fn reassigned_weight_must_stay_within_bounds() {
+        let mut signer_pool = vec![signer(1, 10, true), signer(2, 40, true), signer(3, 44, true)];
+        let removed = signer_pool[0].account_hash;
+        let targets = [signer_pool[1].account_hash, signer_pool[2].account_hash];
+
+        // Each target gains 5, taking the second to 49
+        assert_eq!(
+            reassign_signer_weight(&mut signer_pool, removed, &targets, 48),
+            Err(casper_types::ApiError::InvalidArgument)
+        );
+        assert_eq!(signer_pool.len(), 3);
+        assert_eq!(total_weight(&signer_pool), 94);
+
+        assert_eq!(reassign_signer_weight(&mut signer_pool, removed, &targets, 49), Ok(10));
+        assert_eq!(signer_pool[0].weight, 45);
+        assert_eq!(signer_pool[1].weight, 49);
+    }
+
+    #[test]
+    fn only_the_proposed_admin_can_accept() {
+        let current = signer(1, 1, true).account_hash;
+        let proposed = signer(2, 1, true).account_hash;
+        
+        assert_eq!(
+            check_admin_acceptance(None, proposed),
+            Err(casper_types::ApiError::InvalidArgument)
+        );
+        assert_eq!(
+            check_admin_acceptance(Some(proposed), current),
+            Err(casper_types::ApiError::PermissionDenied)
+        );
+        assert_eq!(check_admin_acceptance(Some(proposed), proposed), Ok(proposed));
+    }
+
+    #[test]
+    fn replaced_or_cancelled_proposals_cannot_be_accepted() {
+        let mistyped = signer(2, 1, true).account_hash;
+        let intended = signer(3, 1, true).account_hash;
+
+        let mut pending_admin = Some(mistyped);
+        assert_eq!(check_admin_acceptance(pending_admin, mistyped), Ok(mistyped));
+
+        // A second proposal replaces the first
+        pending_admin = Some(intended);
+        assert_eq!(
+            check_admin_acceptance(pending_admin, mistyped),
+            Err(casper_types::ApiError::PermissionDenied)
+        );
+        assert_eq!(check_admin_acceptance(pending_admin, intended), Ok(intended));
+
+        // Cancelling clears it
+        pending_admin = None;
+        assert_eq!(
+            check_admin_acceptance(pending_admin, intended),
+            Err(casper_types::ApiError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn selection_follows_active_weights() {
+        let signer_pool = vec![signer(1, 1, true), signer(2, 10, false), signer(3, 3, true), signer(4, 6, true)];
+        let draws = 50_000u64;
+        let mut picks = [0u64; 4];
+        // splitmix64, standing in for the block-time seeds the contract mixes
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        for _ in 0..draws {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut seed = state;
+            seed = (seed ^ (seed >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            seed = (seed ^ (seed >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            seed ^= seed >> 31;
+            
+            let chosen = select_weighted_signer(&signer_pool, seed).unwrap();
+            let index = signer_pool.iter().position(|s| s.account_hash == chosen.account_hash).unwrap();
+            picks[index] += 1;
+        }
+        
+        assert_eq!(picks[1], 0);
+        for (index, weight) in [(0, 1u64), (2, 3), (3, 6)] {
+            let expected = draws * weight / 10;
+            assert!(picks[index].abs_diff(expected) < expected / 20, "signer {} picked {} times", index, picks[index]);
+        }
+    }
+
+    #[test]
+    fn deactivated_signer_stays_in_pool_but_is_not_selected() {
+        let mut signer_pool = vec![signer(1, 4, true), signer(2, 6, true)];
+        let paused = signer_pool[1].account_hash;
+        
+        assert_eq!(set_signer_active(&mut signer_pool, paused, false), Ok(()));
+        assert_eq!(signer_pool.len(), 2);
+        assert_eq!(signer_pool[1].weight, 6);
+        for seed in 0..20 {
+            assert_eq!(select_weighted_signer(&signer_pool, seed).unwrap().account_hash, signer_pool[0].account_hash);
+        }
+        
+        assert_eq!(set_signer_active(&mut signer_pool, paused, true), Ok(()));
+        assert!((0..20).any(|seed| select_weighted_signer(&signer_pool, seed).unwrap().account_hash == paused));
+        
+        let unknown = signer(3, 1, true).account_hash;
+        assert_eq!(
+            set_signer_active(&mut signer_pool, unknown, false),
+            Err(casper_types::ApiError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn selection_needs_an_active_weighted_signer() {
+        assert!(select_weighted_signer(&[], 7).is_none());
+        assert!(select_weighted_signer(&[signer(1, 5, false), signer(2, 0, true)], 7).is_none());
+        let only = vec![signer(1, 5, false), signer(2, 2, true)];
+        assert_eq!(select_weighted_signer(&only, 7).unwrap().account_hash, only[1].account_hash);
+    }
+}
@@ -1,12 +1,35 @@
 /// Storage keys for the vault facilitator contract
 pub const ADMIN_KEY: &str = "admin";
+pub const PENDING_ADMIN_KEY: &str = "pending_admin";
 pub const FEE_RECIPIENT_KEY: &str = "fee_recipient";
 pub const BASE_FEE_RATE_KEY: &str = "base_fee_rate";
 pub const MAX_FEE_RATE_KEY: &str = "max_fee_rate";
 pub const IS_PAUSED_KEY: &str = "is_paused";
+pub const PAUSE_REASON_KEY: &str = "pause_reason";
 pub const SUPPORTED_TOKENS_KEY: &str = "supported_tokens";
+pub const FEE_TOKENS_KEY: &str = "fee_tokens";
 pub const SIGNER_POOL_KEY: &str = "signer_pool";
 pub const CONTRACT_HASH_KEY: &str = "contract_hash";
+pub const ADMIN_ACTIONS_KEY: &str = "admin_actions";
+pub const QUOTE_SIGNER_KEY: &str = "quote_signer";
+pub const USED_QUOTES_KEY: &str = "used_quotes";
+pub const TOKEN_FEE_BOUNDS_KEY: &str = "token_fee_bounds";
+pub const TOKEN_TRANSFER_LIMITS_KEY: &str = "token_transfer_limits";
+pub const MAX_TX_SIZE_KEY: &str = "max_tx_size";
+pub const FEES_COLLECTED_KEY: &str = "total_fees_collected";
+pub const COLLECTED_FEES_KEY: &str = "collected_fees";
+pub const MIN_ACTIVE_SIGNERS_KEY: &str = "min_active_signers";
+pub const MAX_SIGNER_WEIGHT_KEY: &str = "max_signer_weight";
+pub const DEFAULT_FEE_TOKEN_KEY: &str = "default_fee_token";
+pub const RESOURCE_PRICES_KEY: &str = "resource_prices";
+pub const MAX_FEE_BPS_OF_AMOUNT_KEY: &str = "max_fee_bps_of_amount";
+pub const REJECT_FEE_ABOVE_BPS_KEY: &str = "reject_fee_above_bps";
+pub const STORAGE_STATS_KEY: &str = "storage_stats";
+pub const DEFAULT_CONGESTION_KEY: &str = "default_congestion";
+pub const CONGESTION_FEEDER_KEY: &str = "congestion_feeder";
+pub const SIGNER_SELECTION_COUNTER_KEY: &str = "signer_selection_counter";
+pub const TX_COUNTER_KEY: &str = "transaction_count";
+pub const PROCESSED_TRANSACTIONS_KEY: &str = "processed_transactions";
 
 /// Fee calculation constants
 pub const BASE_FEE_LAMPORTS: u64 = 100_000; // 0.0001 CSPR
@@ -20,6 +43,16 @@ pub const DEFAULT_MARGIN_MULTIPLIER: f64 = 1.1; // 10% margin
 pub const MIN_FEE_LAMPORTS: u64 = 1_000; // 0.000001 CSPR minimum
 pub const MAX_PRIORITY_FEE_LAMPORTS: u64 = 100_000; // 0.0001 CSPR maximum priority fee
 pub const CONGESTION_MULTIPLIER_BASE: f64 = 0.2; // 20% per congestion level
+pub const MAX_CONGESTION_LEVEL: u8 = 10;
+pub const USE_DEFAULT_CONGESTION: u8 = u8::MAX; // Sentinel: apply the stored default congestion level
+
+/// Registry limits
+pub const MAX_SUPPORTED_TOKENS: usize = 100;
+pub const MAX_ADMIN_ACTIONS: usize = 100; // Oldest audit entries are dropped beyond this
+pub const DEFAULT_MAX_TX_SIZE: u64 = 1_000_000; // Bytes of transaction_data accepted for processing
+pub const DEFAULT_MIN_ACTIVE_SIGNERS: u32 = 1; // Active signers required before payments are processed
+pub const DEFAULT_MAX_SIGNER_WEIGHT: u32 = 1_000; // Bound on a single signer's selection weight until the admin sets one
+pub const BPS_DENOMINATOR: u64 = 10_000; // Basis points in 100%
 
 /// Admin token utility constants
 pub const DEFAULT_CHUNK_SIZE: usize = 10;
@@ -28,6 +28,36 @@ pub enum FacilitatorError {
     InvalidChunkSize = 1011,
     /// Token account creation failed (1012)
     TokenAccountCreationFailed = 1012,
+    /// Supported token registry is full (1013)
+    TokenLimitExceeded = 1013,
+    /// Fee quote signature or contents are invalid (1014)
+    InvalidQuote = 1014,
+    /// Fee quote has expired (1015)
+    QuoteExpired = 1015,
+    /// Fee exceeds the token's configured maximum (1016)
+    FeeAboveMaximum = 1016,
+    /// Transaction data exceeds the configured size limit (1017)
+    PayloadTooLarge = 1017,
+    /// Token is supported for payments but not accepted for fees (1018)
+    FeeTokenNotEligible = 1018,
+    /// Fewer active signers than the configured minimum (1019)
+    InsufficientSigners = 1019,
+    /// No price is configured for the resource (1020)
+    ResourceNotPriced = 1020,
+    /// Fee exceeds the configured share of the payment amount (1021)
+    FeeAboveAmountShare = 1021,
+    /// No collected fees are held for the token (1022)
+    NoFeesToWithdraw = 1022,
+    /// Payment amount is outside the token's transfer limits (1023)
+    TransferAmountOutOfRange = 1023,
+    /// Transaction signature matches no active signer (1024)
+    InvalidSignature = 1024,
+    /// Fee quote has already been charged (1025)
+    QuoteAlreadyUsed = 1025,
+    /// Transaction has already been processed (1026)
+    TransactionAlreadyProcessed = 1026,
+    /// Payment amount is needed to check the token's transfer limits (1027)
+    PaymentAmountRequired = 1027,
 }
 
 impl From<FacilitatorError> for ApiError {
@@ -87,4 +117,64 @@ pub fn invalid_chunk_size_error() -> ApiError {
 
 pub fn token_account_creation_failed_error() -> ApiError {
     FacilitatorError::TokenAccountCreationFailed.into()
-}
\ No newline at end of file
+}
+
+pub fn token_limit_exceeded_error() -> ApiError {
+    FacilitatorError::TokenLimitExceeded.into()
+}
+
+pub fn invalid_quote_error() -> ApiError {
+    FacilitatorError::InvalidQuote.into()
+}
+
+pub fn quote_expired_error() -> ApiError {
+    FacilitatorError::QuoteExpired.into()
+}
+
+pub fn fee_above_maximum_error() -> ApiError {
+    FacilitatorError::FeeAboveMaximum.into()
+}
+
+pub fn payload_too_large_error() -> ApiError {
+    FacilitatorError::PayloadTooLarge.into()
+}
+
+pub fn fee_token_not_eligible_error() -> ApiError {
+    FacilitatorError::FeeTokenNotEligible.into()
+}
+
+pub fn insufficient_signers_error() -> ApiError {
+    FacilitatorError::InsufficientSigners.into()
+}
+
+pub fn resource_not_priced_error() -> ApiError {
+    FacilitatorError::ResourceNotPriced.into()
+}
+
+pub fn fee_above_amount_share_error() -> ApiError {
+    FacilitatorError::FeeAboveAmountShare.into()
+}
+
+pub fn no_fees_to_withdraw_error() -> ApiError {
+    FacilitatorError::NoFeesToWithdraw.into()
+}
+
+pub fn transfer_amount_out_of_range_error() -> ApiError {
+    FacilitatorError::TransferAmountOutOfRange.into()
+}
+
+pub fn invalid_signature_error() -> ApiError {
+    FacilitatorError::InvalidSignature.into()
+}
+
+pub fn quote_already_used_error() -> ApiError {
+    FacilitatorError::QuoteAlreadyUsed.into()
+}
+
+pub fn transaction_already_processed_error() -> ApiError {
+    FacilitatorError::TransactionAlreadyProcessed.into()
+}
+
+pub fn payment_amount_required_error() -> ApiError {
+    FacilitatorError::PaymentAmountRequired.into()
+}
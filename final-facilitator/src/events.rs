@@ -18,7 +18,7 @@ fn format_event_data(data: Vec<(String, String)>) -> String {
     let mut formatted = String::new();
     for (i, (key, value)) in data.iter().enumerate() {
         if i > 0 {
-            formatted.push_str(",");
+            formatted.push(',');
         }
         formatted.push_str(&format!("{}:{}", key, value));
     }
@@ -95,6 +95,21 @@ pub fn emit_transaction_processed_event(
     emit_facilitator_event("TransactionProcessed", data);
 }
 
+/// Fields of the `TransactionProcessed` event
+///
+/// Only the fee and payload size are kept; nothing derived from the
+/// caller's or the quote signer's signatures is stored.
+pub fn transaction_processed_event_data(fee: u64, size: usize, quoted: bool) -> Vec<(String, String)> {
+    let mut data = vec![
+        ("fee".to_string(), fee.to_string()),
+        ("size".to_string(), size.to_string()),
+    ];
+    if quoted {
+        data.push(("quoted".to_string(), true.to_string()));
+    }
+    data
+}
+
 /// Emit contract paused event
 pub fn emit_contract_paused_event() {
     emit_facilitator_event("ContractPaused", vec![
@@ -102,9 +117,79 @@ pub fn emit_contract_paused_event() {
     ]);
 }
 
+/// Fields of the admin `ContractPaused` event; the reason is empty when
+/// none was given
+pub fn contract_paused_event_data(reason: Option<&str>) -> Vec<(String, String)> {
+    vec![("reason".to_string(), reason.unwrap_or_default().to_string())]
+}
+
 /// Emit contract unpaused event
 pub fn emit_contract_unpaused_event() {
     emit_facilitator_event("ContractUnpaused", vec![
         ("timestamp".to_string(), format!("{}", u64::from(runtime::get_blocktime()))),
     ]);
-}
\ No newline at end of file
+}
+
+/// Emit the unified admin audit event, stored as the typed record
+///
+/// Admin functions still emit their own string events next to this one:
+/// those carry the operation's arguments (the token, signer or pause
+/// reason), which the audit record leaves out, and existing indexers read
+/// them.
+pub fn emit_admin_action_event(record: &crate::types::AdminActionRecord) {
+    let event_key = format!("{}_AdminActionPerformed", FACILITATOR_EVENT_PREFIX);
+    let event_uref = casper_storage::new_uref(record.clone());
+    runtime::put_key(&event_key, event_uref.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signature::{self, encode_hex};
+    use crate::storage;
+    use crate::types::SignerInfo;
+    use casper_types::{account::AccountHash, crypto, PublicKey, SecretKey};
+
+    /// Processing a signed transaction persists only the processed-transaction
+    /// dictionary key and the `TransactionProcessed` event, neither of which
+    /// keeps any of the signature
+    #[test]
+    fn processed_transactions_store_no_signature() {
+        let secret_key = SecretKey::ed25519_from_bytes([8u8; 32]).unwrap();
+        let public_key = PublicKey::from(&secret_key);
+        let signer_pool = [SignerInfo {
+            account_hash: AccountHash::from(&public_key),
+            public_key: public_key.clone(),
+            weight: 1,
+            is_active: true,
+        }];
+        let transaction_data = b"payload";
+        let digest = crypto::blake2b(transaction_data);
+        let signature = encode_hex(&Vec::<u8>::from(crypto::sign(digest, &secret_key, &public_key)));
+
+        // The same steps the contract takes before writing
+        signature::authorize_transaction(&digest, &signature, &signer_pool, false).unwrap();
+        let dictionary_key = storage::processed_transaction_key(&digest);
+        let event_values: Vec<String> = [false, true]
+            .iter()
+            .map(|&quoted| format_event_data(transaction_processed_event_data(2_500, transaction_data.len(), quoted)))
+            .collect();
+
+        assert_eq!(dictionary_key, encode_hex(&digest));
+        assert_eq!(event_values[1], "fee:2500,size:7,quoted:true");
+        // The signature without its tag byte, so only signature bytes can match
+        let signature_body = &signature[2..];
+        for value in event_values.iter().chain([&dictionary_key]) {
+            for window in signature_body.as_bytes().chunks(16) {
+                let window = core::str::from_utf8(window).unwrap();
+                assert!(!value.contains(window), "{} keeps signature bytes {}", value, window);
+            }
+        }
+    }
+
+    #[test]
+    fn pause_event_records_the_reason() {
+        assert_eq!(format_event_data(contract_paused_event_data(Some("upgrade"))), "reason:upgrade");
+        assert_eq!(format_event_data(contract_paused_event_data(None)), "reason:");
+    }
+}
@@ -1,6 +1,10 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use casper_types::{runtime_args, RuntimeArgs};
+
 use crate::constants::*;
 use crate::errors::*;
-use crate::types::FeeCalculation;
+use crate::types::{FeeCalculation, TokenFeeBounds, TokenTransferLimits};
 
 /// Calculate total fees for a transaction with Kora's pricing model
 pub fn calculate_total_fees(
@@ -78,7 +82,7 @@ pub fn estimate_kora_fee(
     
     // Apply fee multiplier if provided
     if let Some(multiplier) = fee_multiplier {
-        if multiplier < 0.0 || multiplier > 10.0 {
+        if !(0.0..=10.0).contains(&multiplier) {
             return Err(invalid_fee_rate_error());
         }
         
@@ -193,12 +197,23 @@ pub fn validate_fee_parameters(
     Ok(())
 }
 
+/// Check that transaction data is non-empty and at most `max_tx_size` bytes
+pub fn validate_transaction_size(size: usize, max_tx_size: u64) -> Result<(), casper_types::ApiError> {
+    if size == 0 {
+        return Err(casper_types::ApiError::InvalidArgument);
+    }
+    if size as u64 > max_tx_size {
+        return Err(payload_too_large_error());
+    }
+    Ok(())
+}
+
 /// Calculate priority fee based on network congestion
 pub fn calculate_priority_fee(
     base_fee: u64,
     congestion_level: u8,
 ) -> Result<u64, casper_types::ApiError> {
-    if congestion_level > 10 {
+    if congestion_level > MAX_CONGESTION_LEVEL {
         return Err(invalid_fee_rate_error());
     }
     
@@ -211,6 +226,44 @@ pub fn calculate_priority_fee(
     Ok(capped_fee)
 }
 
+/// Congestion level for a fee estimate: the requested one, or `default`
+/// when the caller passes [`USE_DEFAULT_CONGESTION`]
+pub fn resolve_congestion_level(requested: u8, default: u8) -> Result<u8, casper_types::ApiError> {
+    let congestion_level = if requested == USE_DEFAULT_CONGESTION {
+        default
+    } else {
+        requested
+    };
+    if congestion_level > MAX_CONGESTION_LEVEL {
+        return Err(invalid_fee_rate_error());
+    }
+    Ok(congestion_level)
+}
+
+/// Surcharge for network congestion: what the priority fee for
+/// `congestion_level` adds on top of the base fee
+pub fn calculate_congestion_surcharge(
+    base_fee: u64,
+    congestion_level: u8,
+) -> Result<u64, casper_types::ApiError> {
+    Ok(calculate_priority_fee(base_fee, congestion_level)?.saturating_sub(base_fee))
+}
+
+/// Congestion surcharge for an optional requested level
+///
+/// No level means no surcharge; [`USE_DEFAULT_CONGESTION`] applies
+/// `default`.
+pub fn resolve_congestion_surcharge(
+    base_fee: u64,
+    requested: Option<u8>,
+    default: u8,
+) -> Result<u64, casper_types::ApiError> {
+    match requested {
+        Some(requested) => calculate_congestion_surcharge(base_fee, resolve_congestion_level(requested, default)?),
+        None => Ok(0),
+    }
+}
+
 /// Convert lamports to token amount using exchange rate
 pub fn convert_lamports_to_token(
     lamports: u64,
@@ -235,4 +288,415 @@ pub fn convert_token_to_lamports(
     
     let lamports = (token_amount as f64 * exchange_rate) as u64;
     Ok(lamports)
-}
\ No newline at end of file
+}
+
+/// Check that fees may be paid in `fee_token`.
+///
+/// Both lists are sorted registries; a fee token must be supported for
+/// payments and also be in the fee-eligible set.
+pub fn validate_fee_token(
+    supported_tokens: &[casper_types::ContractHash],
+    fee_tokens: &[casper_types::ContractHash],
+    fee_token: casper_types::ContractHash,
+) -> Result<(), casper_types::ApiError> {
+    if supported_tokens.binary_search(&fee_token).is_err() {
+        return Err(token_not_supported_error());
+    }
+    if fee_tokens.binary_search(&fee_token).is_err() {
+        return Err(fee_token_not_eligible_error());
+    }
+    Ok(())
+}
+
+/// Add `fee_amount` to the total for `fee_token`, saturating at
+/// `u64::MAX`, and return whether `fee_token` got a new entry
+///
+/// A zero fee is not recorded, so it never creates an entry.
+pub fn record_fee_collected(
+    fees_collected: &mut BTreeMap<casper_types::ContractHash, u64>,
+    fee_token: casper_types::ContractHash,
+    fee_amount: u64,
+) -> bool {
+    if fee_amount == 0 {
+        return false;
+    }
+    let new_entry = !fees_collected.contains_key(&fee_token);
+    let total = fees_collected.entry(fee_token).or_insert(0);
+    *total = total.saturating_add(fee_amount);
+    new_entry
+}
+
+/// CEP-18 `transfer_from` arguments moving `fee_amount` from `payer` to
+/// `recipient`
+pub fn fee_transfer_args(
+    payer: casper_types::account::AccountHash,
+    recipient: casper_types::Key,
+    fee_amount: u64,
+) -> RuntimeArgs {
+    runtime_args! {
+        "owner" => casper_types::Key::Account(payer),
+        "recipient" => recipient,
+        "amount" => casper_types::U256::from(fee_amount),
+    }
+}
+
+/// Take the whole withdrawable balance of `fee_token`, leaving it at zero
+pub fn take_collected_fees(
+    collected_fees: &mut BTreeMap<casper_types::ContractHash, u64>,
+    fee_token: casper_types::ContractHash,
+) -> Result<u64, casper_types::ApiError> {
+    match collected_fees.remove(&fee_token) {
+        Some(amount) if amount > 0 => Ok(amount),
+        _ => Err(no_fees_to_withdraw_error()),
+    }
+}
+
+/// Fee token for a transaction: the one it names, else the deployment
+/// default; `None` means native CSPR
+pub fn resolve_fee_token(
+    fee_token: Option<casper_types::ContractHash>,
+    default_fee_token: Option<casper_types::ContractHash>,
+) -> Option<casper_types::ContractHash> {
+    fee_token.or(default_fee_token)
+}
+
+/// Amount and payment token (`None` is native CSPR) a caller must provide
+/// to use `resource_id`
+pub fn required_payment(
+    resource_prices: &BTreeMap<String, (u64, Option<casper_types::ContractHash>)>,
+    resource_id: &str,
+) -> Result<(u64, Option<casper_types::ContractHash>), casper_types::ApiError> {
+    resource_prices
+        .get(resource_id)
+        .copied()
+        .ok_or_else(resource_not_priced_error)
+}
+
+/// Check that a new maximum fee rate still admits the current base rate
+pub fn validate_max_fee_rate(new_max: u64, base_fee_rate: u64) -> Result<(), casper_types::ApiError> {
+    if new_max < base_fee_rate {
+        return Err(casper_types::ApiError::InvalidArgument);
+    }
+    Ok(())
+}
+
+/// Check that a new base fee rate stays within the maximum fee rate
+pub fn validate_base_fee_rate(new_base: u64, max_fee_rate: u64) -> Result<(), casper_types::ApiError> {
+    validate_max_fee_rate(max_fee_rate, new_base)
+}
+
+/// Cap `fee` at `max_fee_bps` basis points of `payment_amount`.
+///
+/// A fee above the cap is clamped to it, or rejected when
+/// `reject_above_cap` is set. A `max_fee_bps` of 0 disables the cap.
+pub fn apply_max_fee_bps(
+    fee: u64,
+    payment_amount: u64,
+    max_fee_bps: u32,
+    reject_above_cap: bool,
+) -> Result<u64, casper_types::ApiError> {
+    if max_fee_bps == 0 {
+        return Ok(fee);
+    }
+    
+    // Cannot overflow: u64::MAX * u32::MAX fits in a u128
+    let cap = (payment_amount as u128 * max_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+    if fee <= cap {
+        return Ok(fee);
+    }
+    if reject_above_cap {
+        return Err(fee_above_amount_share_error());
+    }
+    Ok(cap)
+}
+
+/// Cap a fee charged in `fee_token` at `max_fee_bps` of a payment of
+/// `payment_amount` in `payment_token`
+///
+/// The share is only meaningful when both amounts are in the same token's
+/// base units, so the fee is left as-is when the payment is in another token
+/// or either side is missing.
+pub fn apply_payment_fee_cap(
+    fee: u64,
+    fee_token: casper_types::ContractHash,
+    payment_token: Option<casper_types::ContractHash>,
+    payment_amount: Option<u64>,
+    max_fee_bps: u32,
+    reject_above_cap: bool,
+) -> Result<u64, casper_types::ApiError> {
+    match (payment_token, payment_amount) {
+        (Some(payment_token), Some(payment_amount)) if payment_token == fee_token => {
+            apply_max_fee_bps(fee, payment_amount, max_fee_bps, reject_above_cap)
+        }
+        _ => Ok(fee),
+    }
+}
+
+/// Raise `fee` to the token's floor and clamp it to its ceiling, if it has
+/// bounds
+///
+/// A fee above the ceiling is rejected instead when `reject_above_max` is
+/// set.
+pub fn apply_fee_bounds(bounds: Option<&TokenFeeBounds>, fee: u64) -> Result<u64, casper_types::ApiError> {
+    let bounds = match bounds {
+        Some(bounds) => bounds,
+        None => return Ok(fee),
+    };
+    
+    if fee > bounds.max_fee {
+        if bounds.reject_above_max {
+            return Err(fee_above_maximum_error());
+        }
+        return Ok(bounds.max_fee);
+    }
+    
+    Ok(fee.max(bounds.min_fee))
+}
+
+/// Check a payment against its token's transfer limits, if it has any
+///
+/// A token with limits cannot be paid in without a `payment_amount` to
+/// check against them.
+pub fn check_transfer_limits(
+    limits: Option<&TokenTransferLimits>,
+    payment_amount: Option<u64>,
+) -> Result<(), casper_types::ApiError> {
+    match (limits, payment_amount) {
+        (None, _) => Ok(()),
+        (Some(_), None) => Err(payment_amount_required_error()),
+        (Some(limits), Some(amount)) if !limits.admits(amount) => Err(transfer_amount_out_of_range_error()),
+        (Some(_), Some(_)) => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use casper_types::ContractHash;
+
+    #[test]
+    fn supported_token_not_eligible_for_fees_is_rejected() {
+        let usdc = ContractHash::new([1u8; 32]);
+        let game_token = ContractHash::new([2u8; 32]);
+        let supported = [usdc, game_token];
+        let fee_tokens = [usdc];
+
+        assert_eq!(validate_fee_token(&supported, &fee_tokens, usdc), Ok(()));
+        assert_eq!(
+            validate_fee_token(&supported, &fee_tokens, game_token),
+            Err(fee_token_not_eligible_error())
+        );
+        assert_eq!(
+            validate_fee_token(&supported, &fee_tokens, ContractHash::new([3u8; 32])),
+            Err(token_not_supported_error())
+        );
+    }
+
+    #[test]
+    fn fee_token_falls_back_to_default_then_native() {
+        let usdc = ContractHash::new([1u8; 32]);
+        let dai = ContractHash::new([2u8; 32]);
+
+        assert_eq!(resolve_fee_token(Some(dai), Some(usdc)), Some(dai));
+        assert_eq!(resolve_fee_token(None, Some(usdc)), Some(usdc));
+        assert_eq!(resolve_fee_token(None, None), None);
+    }
+
+    #[test]
+    fn required_payment_reads_configured_price() {
+        let usdc = ContractHash::new([1u8; 32]);
+        let mut resource_prices = BTreeMap::new();
+        resource_prices.insert("weather-api".to_string(), (2_500_000, Some(usdc)));
+        resource_prices.insert("premium-article".to_string(), (1_000_000_000, None));
+
+        assert_eq!(required_payment(&resource_prices, "weather-api"), Ok((2_500_000, Some(usdc))));
+        assert_eq!(required_payment(&resource_prices, "premium-article"), Ok((1_000_000_000, None)));
+        assert_eq!(required_payment(&resource_prices, "unknown"), Err(resource_not_priced_error()));
+    }
+
+    #[test]
+    fn max_fee_rate_never_drops_below_base_rate() {
+        let base_fee_rate = 1_000;
+        // Raising the maximum
+        assert_eq!(validate_max_fee_rate(5_000, base_fee_rate), Ok(()));
+        // Lowering it, but not below the base rate
+        assert_eq!(validate_max_fee_rate(1_500, base_fee_rate), Ok(()));
+        assert_eq!(validate_max_fee_rate(1_000, base_fee_rate), Ok(()));
+        // Lowering it below the base rate
+        assert_eq!(
+            validate_max_fee_rate(999, base_fee_rate),
+            Err(casper_types::ApiError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn base_fee_rate_never_exceeds_max_rate() {
+        let max_fee_rate = 5_000;
+        assert_eq!(validate_base_fee_rate(1_200, max_fee_rate), Ok(()));
+        assert_eq!(validate_base_fee_rate(5_000, max_fee_rate), Ok(()));
+        assert_eq!(
+            validate_base_fee_rate(5_001, max_fee_rate),
+            Err(casper_types::ApiError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn fee_on_small_payment_is_capped_to_amount_share() {
+        // 5% of a 1_000 unit payment is 50; a 2_000 unit fee would be 200%
+        assert_eq!(apply_max_fee_bps(2_000, 1_000, 500, false), Ok(50));
+        assert_eq!(apply_max_fee_bps(2_000, 1_000, 500, true), Err(fee_above_amount_share_error()));
+
+        // Fees within the cap, or with no cap configured, are untouched
+        assert_eq!(apply_max_fee_bps(40, 1_000, 500, true), Ok(40));
+        assert_eq!(apply_max_fee_bps(2_000, 1_000, 0, true), Ok(2_000));
+        assert_eq!(apply_max_fee_bps(u64::MAX, u64::MAX, 10_000, true), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn fee_cap_only_applies_to_payments_in_the_fee_token() {
+        let usdc = ContractHash::new([1u8; 32]);
+        let dai = ContractHash::new([2u8; 32]);
+
+        assert_eq!(apply_payment_fee_cap(2_000, usdc, Some(usdc), Some(1_000), 500, false), Ok(50));
+        assert_eq!(
+            apply_payment_fee_cap(2_000, usdc, Some(usdc), Some(1_000), 500, true),
+            Err(fee_above_amount_share_error())
+        );
+
+        // A payment in another token, or without an amount, does not cap the fee
+        assert_eq!(apply_payment_fee_cap(2_000, usdc, Some(dai), Some(1_000), 500, true), Ok(2_000));
+        assert_eq!(apply_payment_fee_cap(2_000, usdc, Some(usdc), None, 500, true), Ok(2_000));
+        assert_eq!(apply_payment_fee_cap(2_000, usdc, None, Some(1_000), 500, true), Ok(2_000));
+    }
+
+    #[test]
+    fn default_congestion_applies_when_omitted() {
+        assert_eq!(resolve_congestion_level(USE_DEFAULT_CONGESTION, 4), Ok(4));
+        assert_eq!(resolve_congestion_level(2, 4), Ok(2));
+        assert_eq!(resolve_congestion_level(11, 4), Err(invalid_fee_rate_error()));
+
+        // A surcharge of 20% of the base fee per level, while the priority
+        // fee stays under its cap
+        let base_fee = 10_000;
+        let level = resolve_congestion_level(USE_DEFAULT_CONGESTION, 4).unwrap();
+        assert_eq!(calculate_congestion_surcharge(base_fee, level), Ok(8_000));
+        assert_eq!(calculate_congestion_surcharge(base_fee, 0), Ok(0));
+        assert_eq!(
+            calculate_congestion_surcharge(50_000, 10),
+            Ok(MAX_PRIORITY_FEE_LAMPORTS - 50_000)
+        );
+        assert_eq!(calculate_congestion_surcharge(base_fee, 11), Err(invalid_fee_rate_error()));
+
+        assert_eq!(resolve_congestion_surcharge(base_fee, Some(USE_DEFAULT_CONGESTION), 4), Ok(8_000));
+        assert_eq!(resolve_congestion_surcharge(base_fee, Some(1), 4), Ok(2_000));
+        assert_eq!(resolve_congestion_surcharge(base_fee, None, 4), Ok(0));
+    }
+
+    #[test]
+    fn fees_collected_accumulate_per_token() {
+        let usdc = ContractHash::new([1u8; 32]);
+        let dai = ContractHash::new([2u8; 32]);
+        let mut fees_collected = BTreeMap::new();
+
+        assert!(record_fee_collected(&mut fees_collected, usdc, 100_000));
+        assert!(record_fee_collected(&mut fees_collected, dai, 7));
+        assert!(!record_fee_collected(&mut fees_collected, usdc, 250_000));
+        assert_eq!(fees_collected.get(&usdc), Some(&350_000));
+        assert_eq!(fees_collected.get(&dai), Some(&7));
+
+        record_fee_collected(&mut fees_collected, dai, u64::MAX);
+        assert_eq!(fees_collected.get(&dai), Some(&u64::MAX));
+    }
+
+    #[test]
+    fn zero_fees_leave_no_counter_entry() {
+        let usdc = ContractHash::new([1u8; 32]);
+        let mut fees_collected = BTreeMap::new();
+
+        assert!(!record_fee_collected(&mut fees_collected, usdc, 0));
+        assert!(fees_collected.is_empty());
+
+        assert!(record_fee_collected(&mut fees_collected, usdc, 5));
+        assert!(!record_fee_collected(&mut fees_collected, usdc, 0));
+        assert_eq!(fees_collected.get(&usdc), Some(&5));
+    }
+
+    #[test]
+    fn withdrawal_takes_fees_accumulated_across_transactions() {
+        let usdc = ContractHash::new([1u8; 32]);
+        let dai = ContractHash::new([2u8; 32]);
+        let mut collected_fees = BTreeMap::new();
+        for fee in [100_000, 2_500, 40] {
+            record_fee_collected(&mut collected_fees, usdc, fee);
+        }
+        record_fee_collected(&mut collected_fees, dai, 9);
+
+        assert_eq!(take_collected_fees(&mut collected_fees, usdc), Ok(102_540));
+        assert_eq!(take_collected_fees(&mut collected_fees, usdc), Err(no_fees_to_withdraw_error()));
+        assert_eq!(collected_fees.get(&dai), Some(&9));
+    }
+
+    #[test]
+    fn fee_transfer_moves_the_fee_from_the_payer() {
+        let payer = casper_types::account::AccountHash::new([4u8; 32]);
+        let recipient = casper_types::Key::Hash([5u8; 32]);
+        let args = fee_transfer_args(payer, recipient, 2_500);
+
+        let arg = |name: &str| args.get(name).cloned().unwrap();
+        assert_eq!(args.len(), 3);
+        assert_eq!(arg("owner").into_t::<casper_types::Key>().unwrap(), casper_types::Key::Account(payer));
+        assert_eq!(arg("recipient").into_t::<casper_types::Key>().unwrap(), recipient);
+        assert_eq!(arg("amount").into_t::<casper_types::U256>().unwrap(), casper_types::U256::from(2_500u64));
+    }
+
+    #[test]
+    fn oversized_transaction_data_is_rejected() {
+        assert_eq!(validate_transaction_size(0, DEFAULT_MAX_TX_SIZE), Err(casper_types::ApiError::InvalidArgument));
+        assert_eq!(validate_transaction_size(1, DEFAULT_MAX_TX_SIZE), Ok(()));
+        assert_eq!(validate_transaction_size(DEFAULT_MAX_TX_SIZE as usize, DEFAULT_MAX_TX_SIZE), Ok(()));
+        assert_eq!(
+            validate_transaction_size(DEFAULT_MAX_TX_SIZE as usize + 1, DEFAULT_MAX_TX_SIZE),
+            Err(payload_too_large_error())
+        );
+        assert_eq!(validate_transaction_size(65, 64), Err(payload_too_large_error()));
+    }
+
+    #[test]
+    fn token_fee_bounds_raise_clamp_or_reject() {
+        let mut bounds = TokenFeeBounds {
+            token_contract: ContractHash::new([1u8; 32]),
+            min_fee: 1_000,
+            max_fee: 50_000,
+            reject_above_max: false,
+        };
+
+        assert_eq!(apply_fee_bounds(Some(&bounds), 10), Ok(1_000));
+        assert_eq!(apply_fee_bounds(Some(&bounds), 20_000), Ok(20_000));
+        assert_eq!(apply_fee_bounds(Some(&bounds), 50_000), Ok(50_000));
+        assert_eq!(apply_fee_bounds(Some(&bounds), 90_000), Ok(50_000));
+
+        bounds.reject_above_max = true;
+        assert_eq!(apply_fee_bounds(Some(&bounds), 50_000), Ok(50_000));
+        assert_eq!(apply_fee_bounds(Some(&bounds), 50_001), Err(fee_above_maximum_error()));
+
+        assert_eq!(apply_fee_bounds(None, 90_000), Ok(90_000));
+    }
+
+    #[test]
+    fn payments_are_checked_against_their_token_limits() {
+        let limits = TokenTransferLimits {
+            token_contract: ContractHash::new([1u8; 32]),
+            min_amount: 1_000,
+            max_amount: 5_000,
+        };
+
+        assert_eq!(check_transfer_limits(Some(&limits), Some(999)), Err(transfer_amount_out_of_range_error()));
+        assert_eq!(check_transfer_limits(Some(&limits), Some(1_000)), Ok(()));
+        assert_eq!(check_transfer_limits(Some(&limits), Some(5_000)), Ok(()));
+        assert_eq!(check_transfer_limits(Some(&limits), Some(5_001)), Err(transfer_amount_out_of_range_error()));
+        assert_eq!(check_transfer_limits(Some(&limits), None), Err(payment_amount_required_error()));
+        assert_eq!(check_transfer_limits(None, None), Ok(()));
+        assert_eq!(check_transfer_limits(None, Some(u64::MAX)), Ok(()));
+    }
+}
@@ -1,10 +1,11 @@
 #![no_std]
-#![no_main]
+#![cfg_attr(not(test), no_main)]
 
 extern crate alloc;
 
 use alloc::{
     boxed::Box,
+    collections::BTreeMap,
     format,
     string::{String, ToString},
     vec,
@@ -18,9 +19,16 @@ use casper_contract::{
 
 use casper_types::{
     account::AccountHash,
+    bytesrepr::ToBytes,
     crypto::PublicKey,
+    system::CallStackElement,
+    runtime_args,
+    RuntimeArgs,
     ApiError,
     ContractHash,
+    ContractPackageHash,
+    Key,
+    U256,
 };
 
 // Module declarations
@@ -32,6 +40,8 @@ pub mod admin;
 pub mod fee;
 pub mod price;
 pub mod events;
+pub mod signature;
+pub mod x402_message;
 
 // Re-exports
 pub use constants::*;
@@ -47,21 +57,78 @@ pub fn initialize_contract(
     base_fee_rate: u64,
     max_fee_rate: u64,
 ) {
+    fee::validate_base_fee_rate(base_fee_rate, max_fee_rate).unwrap_or_revert();
+    
     // Store contract configuration
     runtime::put_key(ADMIN_KEY, casper_storage::new_uref(admin).into());
+    let pending_admin: Option<AccountHash> = None;
+    runtime::put_key(PENDING_ADMIN_KEY, casper_storage::new_uref(pending_admin).into());
     runtime::put_key(FEE_RECIPIENT_KEY, casper_storage::new_uref(fee_recipient).into());
     runtime::put_key(BASE_FEE_RATE_KEY, casper_storage::new_uref(base_fee_rate).into());
     runtime::put_key(MAX_FEE_RATE_KEY, casper_storage::new_uref(max_fee_rate).into());
     runtime::put_key(IS_PAUSED_KEY, casper_storage::new_uref(false).into());
+    let pause_reason: Option<String> = None;
+    runtime::put_key(PAUSE_REASON_KEY, casper_storage::new_uref(pause_reason).into());
+    runtime::put_key(MAX_TX_SIZE_KEY, casper_storage::new_uref(DEFAULT_MAX_TX_SIZE).into());
+    runtime::put_key(MIN_ACTIVE_SIGNERS_KEY, casper_storage::new_uref(DEFAULT_MIN_ACTIVE_SIGNERS).into());
+    runtime::put_key(MAX_SIGNER_WEIGHT_KEY, casper_storage::new_uref(DEFAULT_MAX_SIGNER_WEIGHT).into());
+    runtime::put_key(SIGNER_SELECTION_COUNTER_KEY, casper_storage::new_uref(0u64).into());
+    runtime::put_key(TX_COUNTER_KEY, casper_storage::new_uref(0u64).into());
+    casper_storage::new_dictionary(PROCESSED_TRANSACTIONS_KEY).unwrap_or_revert();
+    // No cap on fees relative to the payment amount until the admin sets one
+    runtime::put_key(MAX_FEE_BPS_OF_AMOUNT_KEY, casper_storage::new_uref(0u32).into());
+    runtime::put_key(REJECT_FEE_ABOVE_BPS_KEY, casper_storage::new_uref(false).into());
+    // Uncongested until the admin or the congestion feeder says otherwise
+    runtime::put_key(DEFAULT_CONGESTION_KEY, casper_storage::new_uref(0u8).into());
+    let congestion_feeder: Option<AccountHash> = None;
+    runtime::put_key(CONGESTION_FEEDER_KEY, casper_storage::new_uref(congestion_feeder).into());
     
     // Initialize supported tokens registry
     let supported_tokens: Vec<ContractHash> = Vec::new();
     runtime::put_key(SUPPORTED_TOKENS_KEY, casper_storage::new_uref(supported_tokens).into());
     
+    // Fees are accepted in a subset of the supported tokens
+    let fee_tokens: Vec<ContractHash> = Vec::new();
+    runtime::put_key(FEE_TOKENS_KEY, casper_storage::new_uref(fee_tokens).into());
+    
+    // Native CSPR until the admin sets a default fee token
+    let default_fee_token: Option<ContractHash> = None;
+    runtime::put_key(DEFAULT_FEE_TOKEN_KEY, casper_storage::new_uref(default_fee_token).into());
+    
     // Initialize signer pool
     let signer_pool: Vec<SignerInfo> = Vec::new();
     runtime::put_key(SIGNER_POOL_KEY, casper_storage::new_uref(signer_pool).into());
     
+    // No fee quote signer until the admin configures one
+    let quote_signer: Option<PublicKey> = None;
+    runtime::put_key(QUOTE_SIGNER_KEY, casper_storage::new_uref(quote_signer).into());
+    casper_storage::new_dictionary(USED_QUOTES_KEY).unwrap_or_revert();
+    
+    // No per-token fee bounds until the admin sets them
+    let token_fee_bounds: Vec<TokenFeeBounds> = Vec::new();
+    runtime::put_key(TOKEN_FEE_BOUNDS_KEY, casper_storage::new_uref(token_fee_bounds).into());
+    
+    // No per-token transfer limits until the admin sets them
+    let token_transfer_limits: Vec<TokenTransferLimits> = Vec::new();
+    runtime::put_key(TOKEN_TRANSFER_LIMITS_KEY, casper_storage::new_uref(token_transfer_limits).into());
+    
+    // Lifetime fee counters, kept even after a token is removed
+    let fees_collected: BTreeMap<ContractHash, u64> = BTreeMap::new();
+    runtime::put_key(FEES_COLLECTED_KEY, casper_storage::new_uref(fees_collected).into());
+    let collected_fees: BTreeMap<ContractHash, u64> = BTreeMap::new();
+    runtime::put_key(COLLECTED_FEES_KEY, casper_storage::new_uref(collected_fees).into());
+    
+    // No resources are priced until the admin configures them
+    let resource_prices: BTreeMap<String, (u64, Option<ContractHash>)> = BTreeMap::new();
+    runtime::put_key(RESOURCE_PRICES_KEY, casper_storage::new_uref(resource_prices).into());
+    
+    // Initialize admin audit log
+    let admin_actions: Vec<AdminActionRecord> = Vec::new();
+    runtime::put_key(ADMIN_ACTIONS_KEY, casper_storage::new_uref(admin_actions).into());
+    
+    // Every registry above starts empty
+    runtime::put_key(STORAGE_STATS_KEY, casper_storage::new_uref(StorageStats::default()).into());
+    
     // Emit initialization event
     emit_facilitator_event("Initialized", vec![
         ("admin".to_string(), format!("{:?}", admin)),
@@ -88,23 +155,177 @@ pub fn require_admin() {
     }
 }
 
-/// Add a supported token
-pub fn do_add_supported_token(token_contract: ContractHash) -> Result<(), ApiError> {
+/// Propose a new admin; the handoff completes when `new_admin` accepts
+///
+/// A later proposal replaces an earlier one, so a mistyped account can be
+/// corrected before it ever gains control.
+pub fn do_propose_admin(new_admin: AccountHash) -> Result<(), ApiError> {
     require_admin();
     
-    let mut supported_tokens = storage::get_supported_tokens();
+    storage::set_pending_admin(Some(new_admin));
     
-    // Check if token is already supported
-    if supported_tokens.contains(&token_contract) {
-        return Err(ApiError::InvalidArgument);
+    emit_facilitator_event("AdminProposed", vec![
+        ("current_admin".to_string(), format!("{:?}", get_admin())),
+        ("proposed_admin".to_string(), format!("{:?}", new_admin)),
+    ]);
+    record_admin_action(AdminAction::AdminProposed);
+    
+    Ok(())
+}
+
+/// Accept a pending admin proposal; callable only by the proposed account
+pub fn do_accept_admin() -> Result<(), ApiError> {
+    let pending_admin = admin::check_admin_acceptance(storage::get_pending_admin(), runtime::get_caller())?;
+    
+    let previous_admin = get_admin();
+    storage::set_admin(pending_admin);
+    storage::set_pending_admin(None);
+    
+    emit_facilitator_event("AdminChanged", vec![
+        ("previous_admin".to_string(), format!("{:?}", previous_admin)),
+        ("new_admin".to_string(), format!("{:?}", pending_admin)),
+    ]);
+    record_admin_action(AdminAction::AdminChanged);
+    
+    Ok(())
+}
+
+/// Withdraw a pending admin proposal
+pub fn do_cancel_admin_proposal() -> Result<(), ApiError> {
+    require_admin();
+    
+    let pending_admin = storage::get_pending_admin().ok_or(ApiError::InvalidArgument)?;
+    storage::set_pending_admin(None);
+    
+    emit_facilitator_event("AdminProposalCancelled", vec![
+        ("proposed_admin".to_string(), format!("{:?}", pending_admin)),
+    ]);
+    record_admin_action(AdminAction::AdminProposalCancelled);
+    
+    Ok(())
+}
+
+/// Append an admin operation to the bounded audit log and emit
+/// `AdminActionPerformed`
+fn record_admin_action(action: AdminAction) {
+    let record = AdminActionRecord {
+        action,
+        actor: runtime::get_caller(),
+        blocktime: u64::from(runtime::get_blocktime()),
+    };
+    
+    let mut admin_actions = storage::get_admin_actions();
+    let excess = admin::push_admin_action(&mut admin_actions, record.clone());
+    storage::set_admin_actions(admin_actions);
+    update_storage_stats(|stats| {
+        stats.removed(StoredItem::AdminAction, excess as u32);
+        stats.added(StoredItem::AdminAction, 1);
+    });
+    
+    emit_admin_action_event(&record);
+}
+
+/// Apply a change in registry sizes to the stored entry counts
+fn update_storage_stats(update: impl FnOnce(&mut StorageStats)) {
+    let mut stats = storage::get_storage_stats();
+    update(&mut stats);
+    storage::set_storage_stats(stats);
+}
+
+/// The contract's configuration in one read
+///
+/// The supported-token count comes from the storage stats, so the token
+/// registry itself is not loaded.
+pub fn current_config() -> FacilitatorConfig {
+    FacilitatorConfig {
+        admin: get_admin(),
+        fee_recipient: storage::get_fee_recipient(),
+        base_fee_rate: storage::get_base_fee_rate(),
+        max_fee_rate: storage::get_max_fee_rate(),
+        is_paused: storage::is_paused(),
+        supported_token_count: storage::get_storage_stats().supported_tokens,
+        active_signer_count: admin::get_active_signer_count(),
+    }
+}
+
+/// Get up to `count` most recent admin actions, oldest first
+pub fn get_recent_admin_actions(count: u32) -> Vec<AdminActionRecord> {
+    admin::recent_admin_actions(storage::get_admin_actions(), count)
+}
+
+/// Package hash of this contract, which CEP-18 tokens see as the caller
+/// and hold balances under
+pub fn current_contract_package_hash() -> Option<ContractPackageHash> {
+    match runtime::get_call_stack().last()? {
+        CallStackElement::StoredContract { contract_package_hash, .. } => Some(*contract_package_hash),
+        _ => None,
+    }
+}
+
+/// Hash of this contract.
+///
+/// The installer records it under `CONTRACT_HASH_KEY` in its own account's
+/// named keys, which the contract cannot read, so it is taken from the
+/// call stack instead.
+pub fn current_contract_hash() -> Option<ContractHash> {
+    admin::executing_contract_hash(&runtime::get_call_stack())
+}
+
+/// Read a named argument the caller may omit, `None` when it is absent
+fn get_optional_named_arg<T: casper_types::bytesrepr::FromBytes>(name: &str) -> Option<T> {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        casper_contract::ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
+        )
+    };
+    match casper_types::api_error::result_from(ret) {
+        Ok(()) => Some(runtime::get_named_arg(name)),
+        Err(ApiError::MissingArgument) => None,
+        Err(error) => runtime::revert(error),
     }
+}
+
+/// Add a supported token
+///
+/// The registry is kept sorted by contract hash, so the list returned by
+/// `get_supported_tokens` only depends on which tokens are registered and
+/// not on the order they were added or removed in.
+pub fn do_add_supported_token(token_contract: ContractHash) -> Result<(), ApiError> {
+    require_admin();
     
-    supported_tokens.push(token_contract);
+    let mut supported_tokens = storage::get_supported_tokens();
+    admin::insert_supported_token(&mut supported_tokens, token_contract)?;
     storage::set_supported_tokens(supported_tokens);
+    update_storage_stats(|stats| stats.added(StoredItem::SupportedToken, 1));
     
     emit_facilitator_event("TokenAdded", vec![
         ("token".to_string(), format!("{:?}", token_contract)),
     ]);
+    record_admin_action(AdminAction::TokenAdded);
+    
+    Ok(())
+}
+
+/// Add several supported tokens in one call
+///
+/// The batch is all-or-nothing: if any token is already supported, appears
+/// twice in the batch, or the registry would exceed `MAX_SUPPORTED_TOKENS`,
+/// nothing is written.
+pub fn do_add_supported_tokens(tokens: Vec<ContractHash>) -> Result<(), ApiError> {
+    require_admin();
+    
+    let mut supported_tokens = storage::get_supported_tokens();
+    admin::insert_supported_tokens(&mut supported_tokens, &tokens)?;
+    storage::set_supported_tokens(supported_tokens);
+    update_storage_stats(|stats| stats.added(StoredItem::SupportedToken, tokens.len() as u32));
+    
+    emit_facilitator_event("TokensAdded", vec![
+        ("count".to_string(), tokens.len().to_string()),
+    ]);
+    record_admin_action(AdminAction::TokensAdded);
     
     Ok(())
 }
@@ -115,14 +336,31 @@ pub fn do_remove_supported_token(token_contract: ContractHash) -> Result<(), Api
     
     let mut supported_tokens = storage::get_supported_tokens();
     
-    // Find and remove the token
-    if let Some(pos) = supported_tokens.iter().position(|&x| x == token_contract) {
-        supported_tokens.remove(pos);
+    // Removal preserves the sorted order
+    if admin::remove_supported_token(&mut supported_tokens, token_contract) {
         storage::set_supported_tokens(supported_tokens);
+        update_storage_stats(|stats| stats.removed(StoredItem::SupportedToken, 1));
+        
+        let mut token_fee_bounds = storage::get_token_fee_bounds();
+        token_fee_bounds.retain(|b| b.token_contract != token_contract);
+        storage::set_token_fee_bounds(token_fee_bounds);
+        
+        let mut token_transfer_limits = storage::get_token_transfer_limits();
+        token_transfer_limits.retain(|l| l.token_contract != token_contract);
+        storage::set_token_transfer_limits(token_transfer_limits);
+        
+        let mut fee_tokens = storage::get_fee_tokens();
+        if let Ok(pos) = fee_tokens.binary_search(&token_contract) {
+            fee_tokens.remove(pos);
+            storage::set_fee_tokens(fee_tokens);
+            update_storage_stats(|stats| stats.removed(StoredItem::FeeToken, 1));
+            clear_default_fee_token(token_contract);
+        }
         
         emit_facilitator_event("TokenRemoved", vec![
             ("token".to_string(), format!("{:?}", token_contract)),
         ]);
+        record_admin_action(AdminAction::TokenRemoved);
         
         Ok(())
     } else {
@@ -130,9 +368,146 @@ pub fn do_remove_supported_token(token_contract: ContractHash) -> Result<(), Api
     }
 }
 
+/// Accept fees in a supported token
+pub fn do_add_fee_token(token_contract: ContractHash) -> Result<(), ApiError> {
+    require_admin();
+    
+    if storage::get_supported_tokens().binary_search(&token_contract).is_err() {
+        return Err(token_not_supported_error());
+    }
+    
+    let mut fee_tokens = storage::get_fee_tokens();
+    let pos = match fee_tokens.binary_search(&token_contract) {
+        Ok(_) => return Err(ApiError::InvalidArgument),
+        Err(pos) => pos,
+    };
+    fee_tokens.insert(pos, token_contract);
+    storage::set_fee_tokens(fee_tokens);
+    update_storage_stats(|stats| stats.added(StoredItem::FeeToken, 1));
+    
+    emit_facilitator_event("FeeTokenAdded", vec![
+        ("token".to_string(), format!("{:?}", token_contract)),
+    ]);
+    record_admin_action(AdminAction::FeeTokenAdded);
+    
+    Ok(())
+}
+
+/// Stop accepting fees in a token; it stays supported for payments
+pub fn do_remove_fee_token(token_contract: ContractHash) -> Result<(), ApiError> {
+    require_admin();
+    
+    let mut fee_tokens = storage::get_fee_tokens();
+    let pos = fee_tokens
+        .binary_search(&token_contract)
+        .map_err(|_| ApiError::InvalidArgument)?;
+    fee_tokens.remove(pos);
+    storage::set_fee_tokens(fee_tokens);
+    update_storage_stats(|stats| stats.removed(StoredItem::FeeToken, 1));
+    clear_default_fee_token(token_contract);
+    
+    emit_facilitator_event("FeeTokenRemoved", vec![
+        ("token".to_string(), format!("{:?}", token_contract)),
+    ]);
+    record_admin_action(AdminAction::FeeTokenRemoved);
+    
+    Ok(())
+}
+
+/// Set the fee token used when a transaction names none
+///
+/// The token must be fee-eligible; `None` restores native CSPR.
+pub fn do_set_default_fee_token(token_contract: Option<ContractHash>) -> Result<(), ApiError> {
+    require_admin();
+    
+    if let Some(token_contract) = token_contract {
+        if !fee_token_eligible(token_contract) {
+            return Err(fee_token_not_eligible_error());
+        }
+    }
+    
+    storage::set_default_fee_token(token_contract);
+    
+    emit_facilitator_event("DefaultFeeTokenUpdated", vec![
+        ("token".to_string(), format!("{:?}", token_contract)),
+    ]);
+    record_admin_action(AdminAction::DefaultFeeTokenUpdated);
+    
+    Ok(())
+}
+
+/// Fall back to native CSPR if `token_contract` was the default fee token
+fn clear_default_fee_token(token_contract: ContractHash) {
+    if storage::get_default_fee_token() == Some(token_contract) {
+        storage::set_default_fee_token(None);
+    }
+}
+
+/// Set what callers must pay to use `resource_id`
+///
+/// `token_contract` must be a supported token; `None` prices the resource
+/// in native CSPR (motes).
+pub fn do_set_resource_price(
+    resource_id: String,
+    amount: u64,
+    token_contract: Option<ContractHash>,
+) -> Result<(), ApiError> {
+    require_admin();
+    
+    if resource_id.is_empty() || amount == 0 {
+        return Err(ApiError::InvalidArgument);
+    }
+    if let Some(token_contract) = token_contract {
+        if storage::get_supported_tokens().binary_search(&token_contract).is_err() {
+            return Err(token_not_supported_error());
+        }
+    }
+    
+    let mut resource_prices = storage::get_resource_prices();
+    let replaced = resource_prices.insert(resource_id.clone(), (amount, token_contract));
+    storage::set_resource_prices(resource_prices);
+    if replaced.is_none() {
+        update_storage_stats(|stats| stats.added(StoredItem::ResourcePrice, 1));
+    }
+    
+    emit_facilitator_event("ResourcePriceSet", vec![
+        ("resource_id".to_string(), resource_id),
+        ("amount".to_string(), amount.to_string()),
+        ("token".to_string(), format!("{:?}", token_contract)),
+    ]);
+    record_admin_action(AdminAction::ResourcePriceSet);
+    
+    Ok(())
+}
+
+/// Stop pricing `resource_id`
+pub fn do_remove_resource_price(resource_id: String) -> Result<(), ApiError> {
+    require_admin();
+    
+    let mut resource_prices = storage::get_resource_prices();
+    if resource_prices.remove(&resource_id).is_none() {
+        return Err(resource_not_priced_error());
+    }
+    storage::set_resource_prices(resource_prices);
+    update_storage_stats(|stats| stats.removed(StoredItem::ResourcePrice, 1));
+    
+    emit_facilitator_event("ResourcePriceRemoved", vec![
+        ("resource_id".to_string(), resource_id),
+    ]);
+    record_admin_action(AdminAction::ResourcePriceRemoved);
+    
+    Ok(())
+}
+
+/// Whether fees may be paid in `token_contract`
+pub fn fee_token_eligible(token_contract: ContractHash) -> bool {
+    storage::get_fee_tokens().binary_search(&token_contract).is_ok()
+}
+
 /// Add a signer to the pool
 pub fn do_add_signer(public_key: PublicKey, weight: u32) -> Result<(), ApiError> {
     require_admin();
+    admin::validate_signer_weight(weight, storage::get_max_signer_weight())?;
     
     let account_hash = AccountHash::from(&public_key);
     let signer_info = SignerInfo {
@@ -151,11 +526,13 @@ pub fn do_add_signer(public_key: PublicKey, weight: u32) -> Result<(), ApiError>
     
     signer_pool.push(signer_info);
     storage::set_signer_pool(signer_pool);
+    update_storage_stats(|stats| stats.added(StoredItem::Signer, 1));
     
     emit_facilitator_event("SignerAdded", vec![
         ("signer".to_string(), format!("{:?}", account_hash)),
         ("weight".to_string(), weight.to_string()),
     ]);
+    record_admin_action(AdminAction::SignerAdded);
     
     Ok(())
 }
@@ -169,10 +546,12 @@ pub fn do_remove_signer(account_hash: AccountHash) -> Result<(), ApiError> {
     if let Some(pos) = signer_pool.iter().position(|s| s.account_hash == account_hash) {
         signer_pool.remove(pos);
         storage::set_signer_pool(signer_pool);
+        update_storage_stats(|stats| stats.removed(StoredItem::Signer, 1));
         
         emit_facilitator_event("SignerRemoved", vec![
             ("signer".to_string(), format!("{:?}", account_hash)),
         ]);
+        record_admin_action(AdminAction::SignerRemoved);
         
         Ok(())
     } else {
@@ -180,13 +559,61 @@ pub fn do_remove_signer(account_hash: AccountHash) -> Result<(), ApiError> {
     }
 }
 
-/// Pause the contract
-pub fn do_pause_contract() -> Result<(), ApiError> {
+/// Activate or deactivate a signer without removing it from the pool
+///
+/// Inactive signers keep their weight but are skipped by selection and by
+/// the active-signer checks.
+pub fn do_set_signer_active(account_hash: AccountHash, is_active: bool) -> Result<(), ApiError> {
+    require_admin();
+    
+    let mut signer_pool = storage::get_signer_pool();
+    admin::set_signer_active(&mut signer_pool, account_hash, is_active)?;
+    storage::set_signer_pool(signer_pool);
+    
+    emit_facilitator_event("SignerActivityUpdated", vec![
+        ("signer".to_string(), format!("{:?}", account_hash)),
+        ("is_active".to_string(), is_active.to_string()),
+    ]);
+    record_admin_action(AdminAction::SignerActivityUpdated);
+    
+    Ok(())
+}
+
+/// Remove a signer and hand its weight to other active signers
+///
+/// See [`admin::reassign_signer_weight`] for how the weight is split.
+pub fn do_remove_signer_reassign(account_hash: AccountHash, targets: Vec<AccountHash>) -> Result<(), ApiError> {
+    require_admin();
+    
+    let mut signer_pool = storage::get_signer_pool();
+    let weight = admin::reassign_signer_weight(
+        &mut signer_pool,
+        account_hash,
+        &targets,
+        storage::get_max_signer_weight(),
+    )?;
+    storage::set_signer_pool(signer_pool);
+    update_storage_stats(|stats| stats.removed(StoredItem::Signer, 1));
+    
+    emit_facilitator_event("SignerWeightReassigned", vec![
+        ("signer".to_string(), format!("{:?}", account_hash)),
+        ("weight".to_string(), weight.to_string()),
+        ("targets".to_string(), format!("{:?}", targets)),
+    ]);
+    record_admin_action(AdminAction::SignerWeightReassigned);
+    
+    Ok(())
+}
+
+/// Pause the contract, optionally recording why
+pub fn do_pause_contract(reason: Option<String>) -> Result<(), ApiError> {
     require_admin();
     
     storage::set_paused(true);
+    storage::set_pause_reason(reason.clone());
     
-    emit_facilitator_event("ContractPaused", vec![]);
+    emit_facilitator_event("ContractPaused", contract_paused_event_data(reason.as_deref()));
+    record_admin_action(AdminAction::ContractPaused);
     
     Ok(())
 }
@@ -196,121 +623,639 @@ pub fn do_unpause_contract() -> Result<(), ApiError> {
     require_admin();
     
     storage::set_paused(false);
+    storage::set_pause_reason(None);
     
     emit_facilitator_event("ContractUnpaused", vec![]);
+    record_admin_action(AdminAction::ContractUnpaused);
     
     Ok(())
 }
 
-/// Check if contract is paused
-pub fn require_not_paused() {
-    if storage::is_paused() {
-        runtime::revert(ApiError::PermissionDenied);
-    }
+/// Set (or clear) the public key that signs binding fee quotes
+pub fn do_set_quote_signer(public_key: Option<PublicKey>) -> Result<(), ApiError> {
+    require_admin();
+    
+    storage::set_quote_signer(public_key.clone());
+    
+    emit_facilitator_event("QuoteSignerUpdated", vec![
+        ("signer".to_string(), format!("{:?}", public_key)),
+    ]);
+    record_admin_action(AdminAction::QuoteSignerUpdated);
+    
+    Ok(())
 }
 
-/// Estimate transaction fees
-pub fn estimate_transaction_fees(
-    transaction_size: u64,
-    instruction_count: u32,
-    uses_lookup_tables: bool,
-    is_payment_required: bool,
-) -> FeeCalculation {
-    require_not_paused();
+/// Set the fee floor and ceiling for a supported token
+///
+/// Fees computed for the token below `min_fee` are raised to it; fees above
+/// `max_fee` are clamped to it, or rejected when `reject_above_max` is set.
+pub fn do_set_token_fee_bounds(
+    token_contract: ContractHash,
+    min_fee: u64,
+    max_fee: u64,
+    reject_above_max: bool,
+) -> Result<(), ApiError> {
+    require_admin();
     
-    let base_fee_rate = storage::get_base_fee_rate();
+    if storage::get_supported_tokens().binary_search(&token_contract).is_err() {
+        return Err(token_not_supported_error());
+    }
+    if min_fee > max_fee {
+        return Err(invalid_fee_rate_error());
+    }
     
-    fee::calculate_total_fees(
-        transaction_size,
-        instruction_count,
-        uses_lookup_tables,
-        is_payment_required,
-        base_fee_rate,
-    )
+    let mut token_fee_bounds = storage::get_token_fee_bounds();
+    token_fee_bounds.retain(|b| b.token_contract != token_contract);
+    token_fee_bounds.push(TokenFeeBounds {
+        token_contract,
+        min_fee,
+        max_fee,
+        reject_above_max,
+    });
+    storage::set_token_fee_bounds(token_fee_bounds);
+    
+    emit_facilitator_event("TokenFeeBoundsUpdated", vec![
+        ("token".to_string(), format!("{:?}", token_contract)),
+        ("min_fee".to_string(), min_fee.to_string()),
+        ("max_fee".to_string(), max_fee.to_string()),
+        ("reject_above_max".to_string(), reject_above_max.to_string()),
+    ]);
+    record_admin_action(AdminAction::TokenFeeBoundsUpdated);
+    
+    Ok(())
 }
 
-/// Process a facilitated transaction
-pub fn do_process_transaction(
-    _user_signature: String,
-    transaction_data: Vec<u8>,
-    fee_token: Option<ContractHash>,
+/// Get the fee bounds configured for a token, if any
+pub fn get_fee_bounds_for_token(token_contract: ContractHash) -> Option<TokenFeeBounds> {
+    storage::get_token_fee_bounds()
+        .into_iter()
+        .find(|b| b.token_contract == token_contract)
+}
+
+/// Apply a token's fee floor and ceiling to a computed fee
+fn apply_token_fee_bounds(token_contract: ContractHash, fee: u64) -> Result<u64, ApiError> {
+    fee::apply_fee_bounds(get_fee_bounds_for_token(token_contract).as_ref(), fee)
+}
+
+/// Set the smallest and largest payment accepted in a supported token
+pub fn do_set_token_limits(
+    token_contract: ContractHash,
+    min_amount: u64,
+    max_amount: u64,
 ) -> Result<(), ApiError> {
-    require_not_paused();
+    require_admin();
     
-    // Validate transaction data
-    if transaction_data.is_empty() {
+    if storage::get_supported_tokens().binary_search(&token_contract).is_err() {
+        return Err(token_not_supported_error());
+    }
+    if min_amount > max_amount {
         return Err(ApiError::InvalidArgument);
     }
     
-    // Calculate fees
-    let fee_calc = estimate_transaction_fees(
-        transaction_data.len() as u64,
-        1, // Simplified instruction count
-        false,
-        fee_token.is_some(),
-    );
-    
-    // Process fee payment if required
-    if let Some(token_contract) = fee_token {
-        process_fee_payment(token_contract, fee_calc.total_fee)?;
-    }
+    let mut token_transfer_limits = storage::get_token_transfer_limits();
+    token_transfer_limits.retain(|l| l.token_contract != token_contract);
+    token_transfer_limits.push(TokenTransferLimits {
+        token_contract,
+        min_amount,
+        max_amount,
+    });
+    storage::set_token_transfer_limits(token_transfer_limits);
     
-    // Emit transaction processed event
-    emit_facilitator_event("TransactionProcessed", vec![
-        ("fee".to_string(), fee_calc.total_fee.to_string()),
-        ("size".to_string(), transaction_data.len().to_string()),
+    emit_facilitator_event("TokenLimitsUpdated", vec![
+        ("token".to_string(), format!("{:?}", token_contract)),
+        ("min_amount".to_string(), min_amount.to_string()),
+        ("max_amount".to_string(), max_amount.to_string()),
     ]);
+    record_admin_action(AdminAction::TokenLimitsUpdated);
     
     Ok(())
 }
 
-/// Process fee payment in tokens
-fn process_fee_payment(token_contract: ContractHash, _fee_amount: u64) -> Result<(), ApiError> {
-    let supported_tokens = storage::get_supported_tokens();
+/// Get the transfer limits configured for a token, if any
+pub fn get_transfer_limits_for_token(token_contract: ContractHash) -> Option<TokenTransferLimits> {
+    storage::get_token_transfer_limits()
+        .into_iter()
+        .find(|l| l.token_contract == token_contract)
+}
+
+/// Reject a payment outside its token's transfer limits, or one whose
+/// amount is omitted while the token has limits
+fn check_token_transfer_limits(token_contract: ContractHash, amount: Option<u64>) -> Result<(), ApiError> {
+    fee::check_transfer_limits(get_transfer_limits_for_token(token_contract).as_ref(), amount)
+}
+
+/// Change the base fee rate
+///
+/// The new rate may not exceed the current maximum fee rate.
+pub fn do_set_base_fee_rate(new_rate: u64) -> Result<(), ApiError> {
+    require_admin();
     
-    if !supported_tokens.contains(&token_contract) {
-        return Err(ApiError::InvalidArgument);
-    }
+    fee::validate_base_fee_rate(new_rate, storage::get_max_fee_rate())?;
+    
+    let old_rate = storage::get_base_fee_rate();
+    storage::set_base_fee_rate(new_rate);
     
-    // In a real implementation, this would interact with the token contract
-    // to transfer fees from the user to the fee recipient
+    emit_facilitator_event("FeeRateChanged", vec![
+        ("old_base_fee_rate".to_string(), old_rate.to_string()),
+        ("base_fee_rate".to_string(), new_rate.to_string()),
+    ]);
+    record_admin_action(AdminAction::BaseFeeRateUpdated);
     
     Ok(())
 }
 
-/// Create entry points for the contract
-fn create_entry_points() -> casper_types::EntryPoints {
-    let mut entry_points = casper_types::EntryPoints::new();
+/// Change the maximum fee rate
+///
+/// The new maximum may not fall below the current base fee rate.
+pub fn do_set_max_fee_rate(new_max: u64) -> Result<(), ApiError> {
+    require_admin();
     
-    // Admin functions
-    entry_points.add_entry_point(casper_types::EntryPoint::new(
-        "add_supported_token",
-        vec![casper_types::Parameter::new("token_contract", casper_types::CLType::Key)],
-        casper_types::CLType::Unit,
-        casper_types::EntryPointAccess::Public,
-        casper_types::EntryPointType::Contract,
-    ));
+    fee::validate_max_fee_rate(new_max, storage::get_base_fee_rate())?;
     
-    entry_points.add_entry_point(casper_types::EntryPoint::new(
-        "remove_supported_token",
-        vec![casper_types::Parameter::new("token_contract", casper_types::CLType::Key)],
-        casper_types::CLType::Unit,
-        casper_types::EntryPointAccess::Public,
-        casper_types::EntryPointType::Contract,
-    ));
+    let old_max = storage::get_max_fee_rate();
+    storage::set_max_fee_rate(new_max);
     
-    entry_points.add_entry_point(casper_types::EntryPoint::new(
-        "add_signer",
-        vec![
-            casper_types::Parameter::new("public_key", casper_types::CLType::PublicKey),
-            casper_types::Parameter::new("weight", casper_types::CLType::U32),
-        ],
-        casper_types::CLType::Unit,
-        casper_types::EntryPointAccess::Public,
-        casper_types::EntryPointType::Contract,
-    ));
+    emit_facilitator_event("MaxFeeRateUpdated", vec![
+        ("old_max_fee_rate".to_string(), old_max.to_string()),
+        ("max_fee_rate".to_string(), new_max.to_string()),
+    ]);
+    record_admin_action(AdminAction::MaxFeeRateUpdated);
     
-    entry_points.add_entry_point(casper_types::EntryPoint::new(
+    Ok(())
+}
+
+/// Cap token fees at `max_fee_bps` basis points of the payment amount
+///
+/// The cap only applies when the payment is in the fee token. Fees above the cap are clamped to it, or rejected when `reject_above_cap`
+/// is set. Zero disables the cap.
+pub fn do_set_max_fee_bps(max_fee_bps: u32, reject_above_cap: bool) -> Result<(), ApiError> {
+    require_admin();
+    
+    if max_fee_bps as u64 > BPS_DENOMINATOR {
+        return Err(invalid_fee_rate_error());
+    }
+    
+    storage::set_max_fee_bps(max_fee_bps);
+    storage::set_reject_fee_above_bps(reject_above_cap);
+    
+    emit_facilitator_event("MaxFeeBpsUpdated", vec![
+        ("max_fee_bps".to_string(), max_fee_bps.to_string()),
+        ("reject_above_cap".to_string(), reject_above_cap.to_string()),
+    ]);
+    record_admin_action(AdminAction::MaxFeeBpsUpdated);
+    
+    Ok(())
+}
+
+/// Set the congestion level applied to fee estimates that name none
+///
+/// Callable by the admin or the configured congestion feeder.
+pub fn do_set_default_congestion(congestion_level: u8) -> Result<(), ApiError> {
+    let caller = runtime::get_caller();
+    if caller != get_admin() && storage::get_congestion_feeder() != Some(caller) {
+        return Err(ApiError::PermissionDenied);
+    }
+    
+    if congestion_level > MAX_CONGESTION_LEVEL {
+        return Err(invalid_fee_rate_error());
+    }
+    
+    storage::set_default_congestion(congestion_level);
+    
+    emit_facilitator_event("DefaultCongestionUpdated", vec![
+        ("congestion_level".to_string(), congestion_level.to_string()),
+    ]);
+    record_admin_action(AdminAction::DefaultCongestionUpdated);
+    
+    Ok(())
+}
+
+/// Set (or clear) the account allowed to update the default congestion
+pub fn do_set_congestion_feeder(feeder: Option<AccountHash>) -> Result<(), ApiError> {
+    require_admin();
+    
+    storage::set_congestion_feeder(feeder);
+    
+    emit_facilitator_event("CongestionFeederUpdated", vec![
+        ("feeder".to_string(), format!("{:?}", feeder)),
+    ]);
+    record_admin_action(AdminAction::CongestionFeederUpdated);
+    
+    Ok(())
+}
+
+/// Set the maximum `transaction_data` size accepted for processing
+pub fn do_set_max_tx_size(max_tx_size: u64) -> Result<(), ApiError> {
+    require_admin();
+    
+    if max_tx_size == 0 {
+        return Err(ApiError::InvalidArgument);
+    }
+    
+    storage::set_max_tx_size(max_tx_size);
+    
+    emit_facilitator_event("MaxTxSizeUpdated", vec![
+        ("max_tx_size".to_string(), max_tx_size.to_string()),
+    ]);
+    record_admin_action(AdminAction::MaxTxSizeUpdated);
+    
+    Ok(())
+}
+
+/// Set how many active signers are required before payments are processed
+///
+/// Zero disables the check.
+pub fn do_set_min_active_signers(min_active_signers: u32) -> Result<(), ApiError> {
+    require_admin();
+    
+    storage::set_min_active_signers(min_active_signers);
+    
+    emit_facilitator_event("MinActiveSignersUpdated", vec![
+        ("min_active_signers".to_string(), min_active_signers.to_string()),
+    ]);
+    record_admin_action(AdminAction::MinActiveSignersUpdated);
+    
+    Ok(())
+}
+
+/// Set the upper bound on a single signer's weight
+///
+/// Signers already above a lowered bound keep their weight; the bound
+/// applies to weights set from then on.
+pub fn do_set_max_signer_weight(max_signer_weight: u32) -> Result<(), ApiError> {
+    require_admin();
+    
+    if max_signer_weight == 0 {
+        return Err(ApiError::InvalidArgument);
+    }
+    
+    storage::set_max_signer_weight(max_signer_weight);
+    
+    emit_facilitator_event("MaxSignerWeightUpdated", vec![
+        ("max_signer_weight".to_string(), max_signer_weight.to_string()),
+    ]);
+    record_admin_action(AdminAction::MaxSignerWeightUpdated);
+    
+    Ok(())
+}
+
+/// Pick an active signer, weighted by signer weight
+///
+/// The seed is the blake2b hash of the block time and a stored selection
+/// counter, so repeated selections within one block still vary.
+pub fn select_signer() -> Option<SignerInfo> {
+    let counter = storage::get_signer_selection_counter();
+    storage::set_signer_selection_counter(counter.wrapping_add(1));
+    
+    let mut seed_input = u64::from(runtime::get_blocktime()).to_le_bytes().to_vec();
+    seed_input.extend_from_slice(&counter.to_le_bytes());
+    let digest = runtime::blake2b(seed_input);
+    let mut seed = [0u8; 8];
+    seed.copy_from_slice(&digest[..8]);
+    
+    admin::select_weighted_signer(&storage::get_signer_pool(), u64::from_le_bytes(seed)).cloned()
+}
+
+/// Reject processing while the signer pool is below its minimum size
+fn require_enough_signers() -> Result<(), ApiError> {
+    admin::check_active_signers(&storage::get_signer_pool(), storage::get_min_active_signers())
+}
+
+/// Reject empty or over-limit transaction data
+///
+/// Runs before any hashing or fee computation, so an oversized payload
+/// costs the caller nothing beyond the size check.
+fn validate_transaction_data(transaction_data: &[u8]) -> Result<(), ApiError> {
+    fee::validate_transaction_size(transaction_data.len(), storage::get_max_tx_size())
+}
+
+/// Check if contract is paused
+pub fn require_not_paused() {
+    if storage::is_paused() {
+        runtime::revert(ApiError::PermissionDenied);
+    }
+}
+
+/// Estimate transaction fees
+pub fn estimate_transaction_fees(
+    transaction_size: u64,
+    instruction_count: u32,
+    uses_lookup_tables: bool,
+    is_payment_required: bool,
+) -> FeeCalculation {
+    require_not_paused();
+    
+    let base_fee_rate = storage::get_base_fee_rate();
+    
+    fee::calculate_total_fees(
+        transaction_size,
+        instruction_count,
+        uses_lookup_tables,
+        is_payment_required,
+        base_fee_rate,
+    )
+}
+
+/// Lifetime fees collected in `token_contract`, in its base units
+pub fn total_fees_collected(token_contract: ContractHash) -> u64 {
+    storage::get_fees_collected()
+        .get(&token_contract)
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Check `user_signature` over `transaction_data` and record the transaction
+/// as processed, so the signature cannot be replayed
+///
+/// `user_signature` must be a signature by an active pool signer over the
+/// blake2b-256 hash of `transaction_data`.
+fn authorize_transaction(user_signature: &str, transaction_data: &[u8]) -> Result<(), ApiError> {
+    let transaction_digest = runtime::blake2b(transaction_data);
+    signature::authorize_transaction(
+        &transaction_digest,
+        user_signature,
+        &storage::get_signer_pool(),
+        storage::is_transaction_processed(&transaction_digest),
+    )?;
+    if signature::SIGNATURES_UNCHECKED {
+        emit_facilitator_event("SignatureCheckSkipped", vec![
+            ("warning".to_string(), "unchecked-signatures build; transaction signature was not verified".to_string()),
+        ]);
+    }
+    storage::mark_transaction_processed(&transaction_digest);
+    Ok(())
+}
+
+/// Congestion surcharge on `base_fee` for an optional requested level
+///
+/// Without a level there is no surcharge; `USE_DEFAULT_CONGESTION` applies
+/// the stored default level.
+fn congestion_surcharge(base_fee: u64, congestion_level: Option<u8>) -> Result<u64, ApiError> {
+    fee::resolve_congestion_surcharge(base_fee, congestion_level, storage::get_default_congestion())
+}
+
+/// Process a facilitated transaction
+///
+/// `user_signature` must be a signature by an active pool signer over the
+/// blake2b-256 hash of `transaction_data`, and each transaction is processed
+/// at most once. `congestion_level` adds the same surcharge `estimate_fees`
+/// quotes for it.
+pub fn do_process_transaction(
+    user_signature: String,
+    transaction_data: Vec<u8>,
+    fee_token: Option<ContractHash>,
+    payment_token: Option<ContractHash>,
+    payment_amount: Option<u64>,
+    congestion_level: Option<u8>,
+) -> Result<(), ApiError> {
+    require_not_paused();
+    require_enough_signers()?;
+    
+    // Validate transaction data before computing any fees
+    validate_transaction_data(&transaction_data)?;
+    
+    authorize_transaction(&user_signature, &transaction_data)?;
+    
+    if let Some(payment_token) = payment_token {
+        check_token_transfer_limits(payment_token, payment_amount)?;
+    }
+    
+    let fee_token = fee::resolve_fee_token(fee_token, storage::get_default_fee_token());
+    
+    // Calculate fees
+    let fee_calc = estimate_transaction_fees(
+        transaction_data.len() as u64,
+        1, // Simplified instruction count
+        false,
+        fee_token.is_some(),
+    );
+    
+    // Process fee payment if required, within the token's fee bounds
+    let surcharge = congestion_surcharge(fee_calc.base_fee, congestion_level)?;
+    let mut fee = fee_calc.total_fee.saturating_add(surcharge);
+    if let Some(token_contract) = fee_token {
+        fee = apply_token_fee_bounds(token_contract, fee)?;
+        fee = fee::apply_payment_fee_cap(
+            fee,
+            token_contract,
+            payment_token,
+            payment_amount,
+            storage::get_max_fee_bps(),
+            storage::get_reject_fee_above_bps(),
+        )?;
+        process_fee_payment(token_contract, fee)?;
+    }
+    
+    // Emit transaction processed event
+    emit_facilitator_event(
+        "TransactionProcessed",
+        transaction_processed_event_data(fee, transaction_data.len(), false),
+    );
+    count_processed_transaction();
+    
+    Ok(())
+}
+
+/// Process a facilitated transaction at a pre-agreed, server-signed fee
+///
+/// Instead of recomputing the fee, the quoted `fee_amount` is charged as-is
+/// once the quote is checked. The quote signer signs the blake2b-256 hash of
+/// the quote's bytesrepr encoding (`transaction_hash`, `fee_amount`,
+/// `fee_token`, `expires_at`), and `transaction_hash` must be the blake2b-256
+/// hash of `transaction_data`, so a quote cannot be used for another payload
+/// or fee token. Each quote is charged at most once. As with
+/// `do_process_transaction`, `user_signature` must be by an active pool
+/// signer over the hash of `transaction_data`.
+pub fn do_process_transaction_with_quote(
+    user_signature: String,
+    quote: FeeQuote,
+    quote_signature: String,
+    transaction_data: Vec<u8>,
+) -> Result<(), ApiError> {
+    require_not_paused();
+    require_enough_signers()?;
+    
+    validate_transaction_data(&transaction_data)?;
+    authorize_transaction(&user_signature, &transaction_data)?;
+    
+    let quote_signer = storage::get_quote_signer().ok_or_else(invalid_quote_error)?;
+    
+    let quote_bytes = quote.to_bytes().map_err(|_| invalid_quote_error())?;
+    let quote_digest = runtime::blake2b(quote_bytes);
+    signature::check_fee_quote(
+        &quote,
+        &quote_digest,
+        &quote_signature,
+        &quote_signer,
+        &runtime::blake2b(&transaction_data),
+        u64::from(runtime::get_blocktime()),
+        storage::is_quote_used(&quote_digest),
+    )?;
+    if signature::SIGNATURES_UNCHECKED {
+        emit_facilitator_event("SignatureCheckSkipped", vec![
+            ("warning".to_string(), "unchecked-signatures build; quote signature was not verified".to_string()),
+        ]);
+    }
+    storage::mark_quote_used(&quote_digest);
+    
+    if let Some(token_contract) = quote.fee_token {
+        process_fee_payment(token_contract, quote.fee_amount)?;
+    }
+    
+    emit_facilitator_event(
+        "TransactionProcessed",
+        transaction_processed_event_data(quote.fee_amount, transaction_data.len(), true),
+    );
+    count_processed_transaction();
+    
+    Ok(())
+}
+
+/// Add one to the processed-transaction counter, saturating at `u64::MAX`
+fn count_processed_transaction() {
+    storage::set_transaction_count(storage::get_transaction_count().saturating_add(1));
+}
+
+/// Process fee payment in tokens
+///
+/// Moves `fee_amount` from the caller into this contract with the token's
+/// CEP-18 `transfer_from`, so the caller must first have approved this
+/// contract for at least the fee. A failed transfer reverts the whole call.
+/// The fee is held until the admin withdraws it with `withdraw_fees`.
+fn process_fee_payment(token_contract: ContractHash, fee_amount: u64) -> Result<(), ApiError> {
+    fee::validate_fee_token(
+        &storage::get_supported_tokens(),
+        &storage::get_fee_tokens(),
+        token_contract,
+    )?;
+    
+    // A zero fee moves nothing and leaves no counter entry behind
+    if fee_amount == 0 {
+        return Ok(());
+    }
+    
+    let contract_package_hash = current_contract_package_hash().unwrap_or_revert();
+    runtime::call_contract::<()>(
+        token_contract,
+        "transfer_from",
+        fee::fee_transfer_args(runtime::get_caller(), Key::from(contract_package_hash), fee_amount),
+    );
+    
+    let mut collected_fees = storage::get_collected_fees();
+    fee::record_fee_collected(&mut collected_fees, token_contract, fee_amount);
+    storage::set_collected_fees(collected_fees);
+    
+    let mut fees_collected = storage::get_fees_collected();
+    let new_counter = fee::record_fee_collected(&mut fees_collected, token_contract, fee_amount);
+    storage::set_fees_collected(fees_collected);
+    if new_counter {
+        update_storage_stats(|stats| stats.added(StoredItem::FeeCounter, 1));
+    }
+    
+    Ok(())
+}
+
+/// Send all fees held in `token_contract` to `to`
+///
+/// Uses the token's CEP-18 `transfer` from this contract's balance and
+/// resets the withdrawable amount; lifetime totals are kept.
+pub fn do_withdraw_fees(token_contract: ContractHash, to: AccountHash) -> Result<(), ApiError> {
+    require_admin();
+    
+    let mut collected_fees = storage::get_collected_fees();
+    let amount = fee::take_collected_fees(&mut collected_fees, token_contract)?;
+    storage::set_collected_fees(collected_fees);
+    
+    runtime::call_contract::<()>(
+        token_contract,
+        "transfer",
+        runtime_args! {
+            "recipient" => Key::Account(to),
+            "amount" => U256::from(amount),
+        },
+    );
+    
+    emit_facilitator_event("FeesWithdrawn", vec![
+        ("token".to_string(), format!("{:?}", token_contract)),
+        ("to".to_string(), format!("{:?}", to)),
+        ("amount".to_string(), amount.to_string()),
+    ]);
+    record_admin_action(AdminAction::FeesWithdrawn);
+    
+    Ok(())
+}
+
+/// Create entry points for the contract
+fn create_entry_points() -> casper_types::EntryPoints {
+    let mut entry_points = casper_types::EntryPoints::new();
+    
+    // Admin functions
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "add_supported_token",
+        vec![casper_types::Parameter::new("token_contract", casper_types::CLType::Key)],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "batch_add_supported_tokens",
+        vec![casper_types::Parameter::new(
+            "tokens",
+            casper_types::CLType::List(Box::new(casper_types::CLType::Key)),
+        )],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "remove_supported_token",
+        vec![casper_types::Parameter::new("token_contract", casper_types::CLType::Key)],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "add_fee_token",
+        vec![casper_types::Parameter::new("token_contract", casper_types::CLType::Key)],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "remove_fee_token",
+        vec![casper_types::Parameter::new("token_contract", casper_types::CLType::Key)],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_default_fee_token",
+        vec![casper_types::Parameter::new(
+            "token_contract",
+            casper_types::CLType::Option(Box::new(casper_types::CLType::Key)),
+        )],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "add_signer",
+        vec![
+            casper_types::Parameter::new("public_key", casper_types::CLType::PublicKey),
+            casper_types::Parameter::new("weight", casper_types::CLType::U32),
+        ],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
         "remove_signer",
         vec![casper_types::Parameter::new("account_hash", casper_types::CLType::Key)],
         casper_types::CLType::Unit,
@@ -319,26 +1264,327 @@ fn create_entry_points() -> casper_types::EntryPoints {
     ));
     
     entry_points.add_entry_point(casper_types::EntryPoint::new(
-        "pause_contract",
+        "set_signer_active",
+        vec![
+            casper_types::Parameter::new("account_hash", casper_types::CLType::Key),
+            casper_types::Parameter::new("is_active", casper_types::CLType::Bool),
+        ],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "remove_signer_reassign",
+        vec![
+            casper_types::Parameter::new("account_hash", casper_types::CLType::Key),
+            casper_types::Parameter::new(
+                "targets",
+                casper_types::CLType::List(Box::new(casper_types::CLType::Key)),
+            ),
+        ],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "pause_contract",
+        vec![casper_types::Parameter::new(
+            "reason",
+            casper_types::CLType::Option(Box::new(casper_types::CLType::String)),
+        )],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "unpause_contract",
+        vec![],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_max_tx_size",
+        vec![casper_types::Parameter::new("max_tx_size", casper_types::CLType::U64)],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_base_fee_rate",
+        vec![casper_types::Parameter::new("base_fee_rate", casper_types::CLType::U64)],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_max_fee_rate",
+        vec![casper_types::Parameter::new("max_fee_rate", casper_types::CLType::U64)],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_max_fee_bps",
+        vec![
+            casper_types::Parameter::new("max_fee_bps", casper_types::CLType::U32),
+            casper_types::Parameter::new("reject_above_cap", casper_types::CLType::Bool),
+        ],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_max_fee_bps",
+        vec![],
+        casper_types::CLType::U32,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_default_congestion",
+        vec![casper_types::Parameter::new("congestion_level", casper_types::CLType::U8)],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_default_congestion",
+        vec![],
+        casper_types::CLType::U8,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_congestion_feeder",
+        vec![casper_types::Parameter::new(
+            "feeder",
+            casper_types::CLType::Option(Box::new(casper_types::CLType::ByteArray(32))),
+        )],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_min_active_signers",
+        vec![casper_types::Parameter::new("min_active_signers", casper_types::CLType::U32)],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_min_active_signers",
+        vec![],
+        casper_types::CLType::U32,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_max_signer_weight",
+        vec![casper_types::Parameter::new("max_signer_weight", casper_types::CLType::U32)],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_max_signer_weight",
+        vec![],
+        casper_types::CLType::U32,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "propose_admin",
+        vec![casper_types::Parameter::new("new_admin", casper_types::CLType::Key)],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "accept_admin",
+        vec![],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "cancel_admin_proposal",
+        vec![],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    // Query functions
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_pending_admin",
+        vec![],
+        casper_types::CLType::Option(Box::new(casper_types::CLType::ByteArray(32))),
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_pause_reason",
+        vec![],
+        casper_types::CLType::Option(Box::new(casper_types::CLType::String)),
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_contract_hash",
+        vec![],
+        casper_types::CLType::ByteArray(32),
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_supported_tokens",
+        vec![],
+        casper_types::CLType::List(Box::new(casper_types::CLType::Key)),
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_resource_price",
+        vec![
+            casper_types::Parameter::new("resource_id", casper_types::CLType::String),
+            casper_types::Parameter::new("amount", casper_types::CLType::U64),
+            casper_types::Parameter::new(
+                "token_contract",
+                casper_types::CLType::Option(Box::new(casper_types::CLType::Key)),
+            ),
+        ],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "remove_resource_price",
+        vec![casper_types::Parameter::new("resource_id", casper_types::CLType::String)],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_required_payment",
+        vec![casper_types::Parameter::new("resource_id", casper_types::CLType::String)],
+        casper_types::CLType::Tuple2([
+            Box::new(casper_types::CLType::U64),
+            Box::new(casper_types::CLType::Option(Box::new(casper_types::CLType::ByteArray(32)))),
+        ]),
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_default_fee_token",
+        vec![],
+        casper_types::CLType::Option(Box::new(casper_types::CLType::ByteArray(32))),
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "is_fee_token_eligible",
+        vec![casper_types::Parameter::new("token_contract", casper_types::CLType::Key)],
+        casper_types::CLType::Bool,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "withdraw_fees",
+        vec![
+            casper_types::Parameter::new("token_contract", casper_types::CLType::Key),
+            casper_types::Parameter::new("to", casper_types::CLType::Key),
+        ],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_total_fees_collected",
+        vec![casper_types::Parameter::new("token_contract", casper_types::CLType::Key)],
+        casper_types::CLType::U64,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_lifetime_fees",
+        vec![],
+        casper_types::CLType::Map {
+            key: Box::new(casper_types::CLType::ByteArray(32)),
+            value: Box::new(casper_types::CLType::U64),
+        },
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_selected_signer",
+        vec![],
+        casper_types::CLType::Option(Box::new(casper_types::CLType::Tuple2([
+            Box::new(casper_types::CLType::ByteArray(32)),
+            Box::new(casper_types::CLType::PublicKey),
+        ]))),
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_transaction_count",
         vec![],
-        casper_types::CLType::Unit,
+        casper_types::CLType::U64,
         casper_types::EntryPointAccess::Public,
         casper_types::EntryPointType::Contract,
     ));
     
     entry_points.add_entry_point(casper_types::EntryPoint::new(
-        "unpause_contract",
+        "get_config",
         vec![],
-        casper_types::CLType::Unit,
+        casper_types::CLType::Any,
         casper_types::EntryPointAccess::Public,
         casper_types::EntryPointType::Contract,
     ));
     
-    // Query functions
     entry_points.add_entry_point(casper_types::EntryPoint::new(
-        "get_supported_tokens",
+        "get_storage_stats",
         vec![],
-        casper_types::CLType::List(Box::new(casper_types::CLType::Key)),
+        casper_types::CLType::Any,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_admin_actions",
+        vec![casper_types::Parameter::new("count", casper_types::CLType::U32)],
+        casper_types::CLType::List(Box::new(<AdminActionRecord as casper_types::CLTyped>::cl_type())),
         casper_types::EntryPointAccess::Public,
         casper_types::EntryPointType::Contract,
     ));
@@ -350,12 +1596,26 @@ fn create_entry_points() -> casper_types::EntryPoints {
             casper_types::Parameter::new("instruction_count", casper_types::CLType::U32),
             casper_types::Parameter::new("uses_lookup_tables", casper_types::CLType::Bool),
             casper_types::Parameter::new("is_payment_required", casper_types::CLType::Bool),
+            casper_types::Parameter::new("congestion_level", casper_types::CLType::Option(Box::new(casper_types::CLType::U8))),
         ],
         casper_types::CLType::U64,
         casper_types::EntryPointAccess::Public,
         casper_types::EntryPointType::Contract,
     ));
     
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "estimate_fee_breakdown",
+        vec![
+            casper_types::Parameter::new("transaction_size", casper_types::CLType::U64),
+            casper_types::Parameter::new("instruction_count", casper_types::CLType::U32),
+            casper_types::Parameter::new("uses_lookup_tables", casper_types::CLType::Bool),
+            casper_types::Parameter::new("is_payment_required", casper_types::CLType::Bool),
+        ],
+        <FeeCalculation as casper_types::CLTyped>::cl_type(),
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
     // Transaction processing
     entry_points.add_entry_point(casper_types::EntryPoint::new(
         "process_transaction",
@@ -363,12 +1623,83 @@ fn create_entry_points() -> casper_types::EntryPoints {
             casper_types::Parameter::new("user_signature", casper_types::CLType::String),
             casper_types::Parameter::new("transaction_data", casper_types::CLType::List(Box::new(casper_types::CLType::U8))),
             casper_types::Parameter::new("fee_token", casper_types::CLType::Option(Box::new(casper_types::CLType::Key))),
+            casper_types::Parameter::new("payment_token", casper_types::CLType::Option(Box::new(casper_types::CLType::Key))),
+            casper_types::Parameter::new("payment_amount", casper_types::CLType::Option(Box::new(casper_types::CLType::U64))),
+            casper_types::Parameter::new("congestion_level", casper_types::CLType::Option(Box::new(casper_types::CLType::U8))),
+        ],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "process_transaction_with_quote",
+        vec![
+            casper_types::Parameter::new("user_signature", casper_types::CLType::String),
+            casper_types::Parameter::new("quote_transaction_hash", casper_types::CLType::ByteArray(32)),
+            casper_types::Parameter::new("quote_fee", casper_types::CLType::U64),
+            casper_types::Parameter::new("quote_expires_at", casper_types::CLType::U64),
+            casper_types::Parameter::new("quote_signature", casper_types::CLType::String),
+            casper_types::Parameter::new("transaction_data", casper_types::CLType::List(Box::new(casper_types::CLType::U8))),
+            casper_types::Parameter::new("fee_token", casper_types::CLType::Option(Box::new(casper_types::CLType::Key))),
+        ],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_quote_signer",
+        vec![casper_types::Parameter::new(
+            "public_key",
+            casper_types::CLType::Option(Box::new(casper_types::CLType::PublicKey)),
+        )],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_token_fee_bounds",
+        vec![
+            casper_types::Parameter::new("token_contract", casper_types::CLType::Key),
+            casper_types::Parameter::new("min_fee", casper_types::CLType::U64),
+            casper_types::Parameter::new("max_fee", casper_types::CLType::U64),
+            casper_types::Parameter::new("reject_above_max", casper_types::CLType::Bool),
+        ],
+        casper_types::CLType::Unit,
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_token_fee_bounds",
+        vec![casper_types::Parameter::new("token_contract", casper_types::CLType::Key)],
+        casper_types::CLType::Option(Box::new(casper_types::CLType::Any)),
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "set_token_limits",
+        vec![
+            casper_types::Parameter::new("token_contract", casper_types::CLType::Key),
+            casper_types::Parameter::new("min_amount", casper_types::CLType::U64),
+            casper_types::Parameter::new("max_amount", casper_types::CLType::U64),
         ],
         casper_types::CLType::Unit,
         casper_types::EntryPointAccess::Public,
         casper_types::EntryPointType::Contract,
     ));
     
+    entry_points.add_entry_point(casper_types::EntryPoint::new(
+        "get_token_limits",
+        vec![casper_types::Parameter::new("token_contract", casper_types::CLType::Key)],
+        casper_types::CLType::Option(Box::new(casper_types::CLType::Any)),
+        casper_types::EntryPointAccess::Public,
+        casper_types::EntryPointType::Contract,
+    ));
+    
     entry_points
 }
 
@@ -407,12 +1738,63 @@ pub extern "C" fn add_supported_token() {
     do_add_supported_token(token_contract).unwrap_or_revert();
 }
 
+#[no_mangle]
+pub extern "C" fn batch_add_supported_tokens() {
+    let tokens: Vec<ContractHash> = runtime::get_named_arg("tokens");
+    do_add_supported_tokens(tokens).unwrap_or_revert();
+}
+
 #[no_mangle]
 pub extern "C" fn remove_supported_token() {
     let token_contract: ContractHash = runtime::get_named_arg("token_contract");
     do_remove_supported_token(token_contract).unwrap_or_revert();
 }
 
+#[no_mangle]
+pub extern "C" fn add_fee_token() {
+    let token_contract: ContractHash = runtime::get_named_arg("token_contract");
+    do_add_fee_token(token_contract).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn remove_fee_token() {
+    let token_contract: ContractHash = runtime::get_named_arg("token_contract");
+    do_remove_fee_token(token_contract).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_default_fee_token() {
+    let token_contract: Option<ContractHash> = runtime::get_named_arg("token_contract");
+    do_set_default_fee_token(token_contract).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_resource_price() {
+    let resource_id: String = runtime::get_named_arg("resource_id");
+    let amount: u64 = runtime::get_named_arg("amount");
+    let token_contract: Option<ContractHash> = runtime::get_named_arg("token_contract");
+    do_set_resource_price(resource_id, amount, token_contract).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn remove_resource_price() {
+    let resource_id: String = runtime::get_named_arg("resource_id");
+    do_remove_resource_price(resource_id).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn get_required_payment() {
+    let resource_id: String = runtime::get_named_arg("resource_id");
+    let result = fee::required_payment(&storage::get_resource_prices(), &resource_id).unwrap_or_revert();
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_default_fee_token() {
+    let result = storage::get_default_fee_token();
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn add_signer() {
     let public_key: PublicKey = runtime::get_named_arg("public_key");
@@ -426,9 +1808,25 @@ pub extern "C" fn remove_signer() {
     do_remove_signer(account_hash).unwrap_or_revert();
 }
 
+#[no_mangle]
+pub extern "C" fn set_signer_active() {
+    let account_hash: AccountHash = runtime::get_named_arg("account_hash");
+    let is_active: bool = runtime::get_named_arg("is_active");
+    do_set_signer_active(account_hash, is_active).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn remove_signer_reassign() {
+    let account_hash: AccountHash = runtime::get_named_arg("account_hash");
+    let targets: Vec<AccountHash> = runtime::get_named_arg("targets");
+    do_remove_signer_reassign(account_hash, targets).unwrap_or_revert();
+}
+
 #[no_mangle]
 pub extern "C" fn pause_contract() {
-    do_pause_contract().unwrap_or_revert();
+    let reason: Option<String> =
+        get_optional_named_arg::<Option<String>>("reason").flatten();
+    do_pause_contract(reason).unwrap_or_revert();
 }
 
 #[no_mangle]
@@ -436,18 +1834,198 @@ pub extern "C" fn unpause_contract() {
     do_unpause_contract().unwrap_or_revert();
 }
 
+#[no_mangle]
+pub extern "C" fn set_min_active_signers() {
+    let min_active_signers: u32 = runtime::get_named_arg("min_active_signers");
+    do_set_min_active_signers(min_active_signers).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn get_min_active_signers() {
+    let result = storage::get_min_active_signers();
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_max_signer_weight() {
+    let max_signer_weight: u32 = runtime::get_named_arg("max_signer_weight");
+    do_set_max_signer_weight(max_signer_weight).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn get_max_signer_weight() {
+    let result = storage::get_max_signer_weight();
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_base_fee_rate() {
+    let base_fee_rate: u64 = runtime::get_named_arg("base_fee_rate");
+    do_set_base_fee_rate(base_fee_rate).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_max_fee_rate() {
+    let max_fee_rate: u64 = runtime::get_named_arg("max_fee_rate");
+    do_set_max_fee_rate(max_fee_rate).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_max_fee_bps() {
+    let max_fee_bps: u32 = runtime::get_named_arg("max_fee_bps");
+    let reject_above_cap: bool = runtime::get_named_arg("reject_above_cap");
+    do_set_max_fee_bps(max_fee_bps, reject_above_cap).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn get_max_fee_bps() {
+    let result = storage::get_max_fee_bps();
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_default_congestion() {
+    let congestion_level: u8 = runtime::get_named_arg("congestion_level");
+    do_set_default_congestion(congestion_level).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn get_default_congestion() {
+    let result = storage::get_default_congestion();
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_congestion_feeder() {
+    let feeder: Option<AccountHash> = runtime::get_named_arg("feeder");
+    do_set_congestion_feeder(feeder).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_max_tx_size() {
+    let max_tx_size: u64 = runtime::get_named_arg("max_tx_size");
+    do_set_max_tx_size(max_tx_size).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn propose_admin() {
+    let new_admin: AccountHash = runtime::get_named_arg("new_admin");
+    do_propose_admin(new_admin).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn accept_admin() {
+    do_accept_admin().unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_admin_proposal() {
+    do_cancel_admin_proposal().unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn get_pending_admin() {
+    let result = storage::get_pending_admin();
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_pause_reason() {
+    let result = storage::get_pause_reason();
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_contract_hash() {
+    let result = current_contract_hash().unwrap_or_revert_with(ApiError::ContractNotFound);
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn get_supported_tokens() {
     let result = storage::get_supported_tokens();
     runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn withdraw_fees() {
+    let token_contract: ContractHash = runtime::get_named_arg("token_contract");
+    let to: AccountHash = runtime::get_named_arg("to");
+    do_withdraw_fees(token_contract, to).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn get_total_fees_collected() {
+    let token_contract: ContractHash = runtime::get_named_arg("token_contract");
+    let result = total_fees_collected(token_contract);
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_lifetime_fees() {
+    let result = storage::get_fees_collected();
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_selected_signer() {
+    let result = select_signer().map(|signer| (signer.account_hash, signer.public_key));
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_transaction_count() {
+    let result = storage::get_transaction_count();
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_config() {
+    let result = current_config();
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_storage_stats() {
+    let result = storage::get_storage_stats();
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_admin_actions() {
+    let count: u32 = runtime::get_named_arg("count");
+    let result = get_recent_admin_actions(count);
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn estimate_fees() {
     let transaction_size: u64 = runtime::get_named_arg("transaction_size");
     let instruction_count: u32 = runtime::get_named_arg("instruction_count");
     let uses_lookup_tables: bool = runtime::get_named_arg("uses_lookup_tables");
     let is_payment_required: bool = runtime::get_named_arg("is_payment_required");
+    let congestion_level: Option<u8> =
+        get_optional_named_arg::<Option<u8>>("congestion_level").flatten();
+    
+    let result = estimate_transaction_fees(
+        transaction_size,
+        instruction_count,
+        uses_lookup_tables,
+        is_payment_required,
+    );
+    let surcharge = congestion_surcharge(result.base_fee, congestion_level).unwrap_or_revert();
+    
+    runtime::ret(casper_types::CLValue::from_t(result.total_fee.saturating_add(surcharge)).unwrap_or_revert());
+}
+
+/// The fee components behind `estimate_fees`, before any congestion
+/// surcharge
+#[no_mangle]
+pub extern "C" fn estimate_fee_breakdown() {
+    let transaction_size: u64 = runtime::get_named_arg("transaction_size");
+    let instruction_count: u32 = runtime::get_named_arg("instruction_count");
+    let uses_lookup_tables: bool = runtime::get_named_arg("uses_lookup_tables");
+    let is_payment_required: bool = runtime::get_named_arg("is_payment_required");
     
     let result = estimate_transaction_fees(
         transaction_size,
@@ -456,7 +2034,7 @@ pub extern "C" fn estimate_fees() {
         is_payment_required,
     );
     
-    runtime::ret(casper_types::CLValue::from_t(result.total_fee).unwrap_or_revert());
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
 }
 
 #[no_mangle]
@@ -464,6 +2042,84 @@ pub extern "C" fn process_transaction() {
     let user_signature: String = runtime::get_named_arg("user_signature");
     let transaction_data: Vec<u8> = runtime::get_named_arg("transaction_data");
     let fee_token: Option<ContractHash> = runtime::get_named_arg("fee_token");
+    let payment_token: Option<ContractHash> =
+        get_optional_named_arg::<Option<ContractHash>>("payment_token").flatten();
+    let payment_amount: Option<u64> =
+        get_optional_named_arg::<Option<u64>>("payment_amount").flatten();
+    let congestion_level: Option<u8> =
+        get_optional_named_arg::<Option<u8>>("congestion_level").flatten();
+    
+    do_process_transaction(
+        user_signature,
+        transaction_data,
+        fee_token,
+        payment_token,
+        payment_amount,
+        congestion_level,
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn process_transaction_with_quote() {
+    let user_signature: String = runtime::get_named_arg("user_signature");
+    let transaction_hash: [u8; 32] = runtime::get_named_arg("quote_transaction_hash");
+    let fee_amount: u64 = runtime::get_named_arg("quote_fee");
+    let expires_at: u64 = runtime::get_named_arg("quote_expires_at");
+    let quote_signature: String = runtime::get_named_arg("quote_signature");
+    let transaction_data: Vec<u8> = runtime::get_named_arg("transaction_data");
+    let fee_token: Option<ContractHash> = runtime::get_named_arg("fee_token");
+    
+    let quote = FeeQuote {
+        transaction_hash,
+        fee_amount,
+        fee_token,
+        expires_at,
+    };
     
-    do_process_transaction(user_signature, transaction_data, fee_token).unwrap_or_revert();
-}
\ No newline at end of file
+    do_process_transaction_with_quote(user_signature, quote, quote_signature, transaction_data).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_quote_signer() {
+    let public_key: Option<PublicKey> = runtime::get_named_arg("public_key");
+    do_set_quote_signer(public_key).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_token_fee_bounds() {
+    let token_contract: ContractHash = runtime::get_named_arg("token_contract");
+    let min_fee: u64 = runtime::get_named_arg("min_fee");
+    let max_fee: u64 = runtime::get_named_arg("max_fee");
+    let reject_above_max: bool = runtime::get_named_arg("reject_above_max");
+    do_set_token_fee_bounds(token_contract, min_fee, max_fee, reject_above_max).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn get_token_fee_bounds() {
+    let token_contract: ContractHash = runtime::get_named_arg("token_contract");
+    let result = get_fee_bounds_for_token(token_contract);
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_token_limits() {
+    let token_contract: ContractHash = runtime::get_named_arg("token_contract");
+    let min_amount: u64 = runtime::get_named_arg("min_amount");
+    let max_amount: u64 = runtime::get_named_arg("max_amount");
+    do_set_token_limits(token_contract, min_amount, max_amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn get_token_limits() {
+    let token_contract: ContractHash = runtime::get_named_arg("token_contract");
+    let result = get_transfer_limits_for_token(token_contract);
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn is_fee_token_eligible() {
+    let token_contract: ContractHash = runtime::get_named_arg("token_contract");
+    let result = fee_token_eligible(token_contract);
+    runtime::ret(casper_types::CLValue::from_t(result).unwrap_or_revert());
+}
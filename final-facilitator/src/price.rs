@@ -1,3 +1,5 @@
+use casper_types::U256;
+
 use crate::constants::*;
 use crate::errors::*;
 use crate::types::PriceConfig;
@@ -25,7 +27,7 @@ impl PriceCalculator {
     
     /// Set margin multiplier for fee calculation
     pub fn with_margin(mut self, margin_multiplier: f64) -> Result<Self, casper_types::ApiError> {
-        if margin_multiplier < 1.0 || margin_multiplier > 5.0 {
+        if !(1.0..=5.0).contains(&margin_multiplier) {
             return Err(invalid_fee_rate_error());
         }
         
@@ -80,20 +82,31 @@ impl PriceCalculator {
     }
     
     /// Calculate fee based on token pricing
+    ///
+    /// Token amounts are `U256` because high-decimal tokens routinely exceed
+    /// `u64`. Amounts (or converted fees) that do not fit in `u64` return a
+    /// fee calculation overflow error rather than panicking or saturating.
     pub fn calculate_token_based_fee(
         &self,
-        token_amount: u64,
+        token_amount: U256,
         exchange_rate: f64,
     ) -> Result<u64, casper_types::ApiError> {
         if exchange_rate <= 0.0 {
             return Err(invalid_fee_rate_error());
         }
         
+        // Checked U256 -> u64 conversion
+        if token_amount > U256::from(u64::MAX) {
+            return Err(fee_calculation_overflow_error());
+        }
+        let token_amount = token_amount.as_u64();
+        
         // Convert token amount to lamports
-        let lamports_equivalent = (token_amount as f64 * exchange_rate) as u64;
+        let lamports_equivalent = checked_f64_to_u64(token_amount as f64 * exchange_rate)?;
         
         // Apply margin
-        let fee_with_margin = (lamports_equivalent as f64 * self.config.margin_multiplier) as u64;
+        let fee_with_margin =
+            checked_f64_to_u64(lamports_equivalent as f64 * self.config.margin_multiplier)?;
         
         // Ensure minimum fee
         let final_fee = fee_with_margin.max(self.config.min_fee_lamports);
@@ -233,6 +246,15 @@ impl FeeBreakdown {
     }
 }
 
+/// Convert a non-negative float to `u64`, failing instead of saturating
+fn checked_f64_to_u64(value: f64) -> Result<u64, casper_types::ApiError> {
+    // u64::MAX as f64 rounds up to 2^64, which is itself out of range
+    if !value.is_finite() || value < 0.0 || value >= u64::MAX as f64 {
+        return Err(fee_calculation_overflow_error());
+    }
+    Ok(value as u64)
+}
+
 /// Create a default price calculator
 pub fn create_default_price_calculator() -> PriceCalculator {
     PriceCalculator::new(BASE_FEE_LAMPORTS)
@@ -256,4 +278,33 @@ pub fn calculate_dynamic_pricing(
 ) -> Result<u64, casper_types::ApiError> {
     let calculator = create_custom_price_calculator(base_fee, margin)?;
     calculator.estimate_total_cost(transaction_size, congestion_level)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_amounts_up_to_u64_max_are_priced() {
+        let calculator = create_default_price_calculator();
+
+        let fee = calculator.calculate_token_based_fee(U256::from(u64::MAX), 1e-9).unwrap();
+        assert!(fee > MIN_FEE_LAMPORTS);
+        assert_eq!(calculator.calculate_token_based_fee(U256::from(1_000u64), 1.0), Ok(1_100));
+    }
+
+    #[test]
+    fn token_amounts_past_u64_max_overflow() {
+        let calculator = create_default_price_calculator();
+
+        assert_eq!(
+            calculator.calculate_token_based_fee(U256::from(u64::MAX) + 1, 1e-9),
+            Err(fee_calculation_overflow_error())
+        );
+        // The amount fits, but the converted fee does not
+        assert_eq!(
+            calculator.calculate_token_based_fee(U256::from(u64::MAX), 1.0),
+            Err(fee_calculation_overflow_error())
+        );
+    }
+}
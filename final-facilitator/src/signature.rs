@@ -0,0 +1,356 @@
+use alloc::{string::String, vec::Vec};
+use casper_types::{ApiError, PublicKey};
+
+use crate::errors::{
+    invalid_quote_error, invalid_signature_error, quote_already_used_error, quote_expired_error,
+    transaction_already_processed_error,
+};
+use crate::types::{FeeQuote, SignerInfo};
+#[cfg(not(feature = "unchecked-signatures"))]
+use casper_types::{crypto, Signature};
+
+/// Decode a hex string (an optional `0x` prefix is accepted)
+pub fn decode_hex(value: &str) -> Result<Vec<u8>, ApiError> {
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    if !value.len().is_multiple_of(2) {
+        return Err(ApiError::InvalidArgument);
+    }
+    
+    value
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = hex_digit(pair[0])?;
+            let low = hex_digit(pair[1])?;
+            Ok((high << 4) | low)
+        })
+        .collect()
+}
+
+/// Encode bytes as lowercase hex, without a prefix
+pub fn encode_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut encoded = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        encoded.push(DIGITS[(byte >> 4) as usize] as char);
+        encoded.push(DIGITS[(byte & 0x0f) as usize] as char);
+    }
+    encoded
+}
+
+fn hex_digit(c: u8) -> Result<u8, ApiError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(ApiError::InvalidArgument),
+    }
+}
+
+/// Whether this build skips signature verification
+pub const SIGNATURES_UNCHECKED: bool = cfg!(feature = "unchecked-signatures");
+
+/// Verify a hex-encoded raw signature over `message` for `public_key`
+///
+/// The signature curve is taken from the public key variant; both Ed25519
+/// and Secp256k1 signatures are 64 raw bytes.
+///
+/// Built with the `unchecked-signatures` feature, every signature is
+/// accepted; see [`SIGNATURES_UNCHECKED`].
+#[cfg(feature = "unchecked-signatures")]
+pub fn verify_signature(_message: &[u8], _signature_hex: &str, _public_key: &PublicKey) -> bool {
+    true
+}
+
+/// Verify a hex-encoded raw signature over `message` for `public_key`
+///
+/// The signature curve is taken from the public key variant; both Ed25519
+/// and Secp256k1 signatures are 64 raw bytes.
+#[cfg(not(feature = "unchecked-signatures"))]
+pub fn verify_signature(message: &[u8], signature_hex: &str, public_key: &PublicKey) -> bool {
+    let bytes = match decode_hex(signature_hex) {
+        Ok(bytes) => bytes,
+        Err(_) => return false,
+    };
+    let raw: [u8; 64] = match bytes.as_slice().try_into() {
+        Ok(raw) => raw,
+        Err(_) => return false,
+    };
+    
+    let signature = match public_key {
+        PublicKey::Ed25519(_) => Signature::ed25519(raw),
+        PublicKey::Secp256k1(_) => Signature::secp256k1(raw),
+        _ => return false,
+    };
+    
+    match signature {
+        Ok(signature) => crypto::verify(message, &signature, public_key).is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// The first active signer in `signer_pool` whose key verifies
+/// `signature_hex` over `message`
+pub fn find_signer<'a>(
+    message: &[u8],
+    signature_hex: &str,
+    signer_pool: &'a [SignerInfo],
+) -> Option<&'a SignerInfo> {
+    signer_pool
+        .iter()
+        .filter(|s| s.is_active)
+        .find(|s| verify_signature(message, signature_hex, &s.public_key))
+}
+
+/// The active signer authorizing a transaction
+///
+/// `signature_hex` must be by an active pool signer over
+/// `transaction_digest`, and a transaction is processed at most once, so a
+/// signature cannot be replayed once `already_processed`.
+pub fn authorize_transaction<'a>(
+    transaction_digest: &[u8; 32],
+    signature_hex: &str,
+    signer_pool: &'a [SignerInfo],
+    already_processed: bool,
+) -> Result<&'a SignerInfo, ApiError> {
+    let signer = find_signer(transaction_digest, signature_hex, signer_pool).ok_or_else(invalid_signature_error)?;
+    if already_processed {
+        return Err(transaction_already_processed_error());
+    }
+    Ok(signer)
+}
+
+/// Check a fee quote before it is charged
+///
+/// `quote_digest` is the blake2b-256 hash of the quote's bytesrepr encoding,
+/// which `quote_signer` must have signed, and `transaction_digest` the hash
+/// of the submitted `transaction_data`, which must be the quoted one. A quote
+/// past `expires_at`, or one already charged, is rejected.
+pub fn check_fee_quote(
+    quote: &FeeQuote,
+    quote_digest: &[u8; 32],
+    quote_signature: &str,
+    quote_signer: &PublicKey,
+    transaction_digest: &[u8; 32],
+    blocktime: u64,
+    already_used: bool,
+) -> Result<(), ApiError> {
+    if *transaction_digest != quote.transaction_hash {
+        return Err(invalid_quote_error());
+    }
+    if !verify_signature(quote_digest, quote_signature, quote_signer) {
+        return Err(invalid_quote_error());
+    }
+    if blocktime > quote.expires_at {
+        return Err(quote_expired_error());
+    }
+    if already_used {
+        return Err(quote_already_used_error());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use casper_types::crypto::AsymmetricType;
+
+    // Shared with the standalone server, which checks the message fields
+    const PERMIT_VECTORS: &str =
+        include_str!("../../facilitator-standalone/tests/fixtures/permit_vectors.json");
+
+    #[test]
+    #[cfg(not(feature = "unchecked-signatures"))]
+    fn permit_vectors_verify_as_expected() {
+        let fixture: serde_json::Value = serde_json::from_str(PERMIT_VECTORS).unwrap();
+        for vector in fixture["vectors"].as_array().unwrap() {
+            let name = vector["name"].as_str().unwrap();
+            let public_key = PublicKey::from_hex(vector["public_key"].as_str().unwrap()).unwrap();
+            let verified = verify_signature(
+                vector["message"].as_str().unwrap().as_bytes(),
+                vector["signature"].as_str().unwrap(),
+                &public_key,
+            );
+            assert_eq!(verified, vector["expected_valid"].as_bool().unwrap(), "{}", name);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "unchecked-signatures"))]
+    fn only_active_pool_keys_authorize_transactions() {
+        use casper_types::account::AccountHash;
+
+        let fixture: serde_json::Value = serde_json::from_str(PERMIT_VECTORS).unwrap();
+        let vector = fixture["vectors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|v| v["expected_valid"].as_bool().unwrap())
+            .unwrap();
+        let message = vector["message"].as_str().unwrap().as_bytes();
+        let signature_hex = vector["signature"].as_str().unwrap();
+        let signer = |public_key: PublicKey, is_active: bool| SignerInfo {
+            account_hash: AccountHash::from(&public_key),
+            public_key,
+            weight: 1,
+            is_active,
+        };
+        let registered = PublicKey::from_hex(vector["public_key"].as_str().unwrap()).unwrap();
+        let unknown = PublicKey::from(&casper_types::SecretKey::ed25519_from_bytes([7u8; 32]).unwrap());
+
+        let signer_pool = [signer(unknown.clone(), true), signer(registered.clone(), true)];
+        let found = find_signer(message, signature_hex, &signer_pool).unwrap();
+        assert_eq!(found.public_key, registered);
+
+        assert!(find_signer(message, signature_hex, &[signer(unknown, true)]).is_none());
+        assert!(find_signer(message, signature_hex, &[signer(registered, false)]).is_none());
+    }
+
+    #[test]
+    #[cfg(not(feature = "unchecked-signatures"))]
+    fn processed_transaction_signature_cannot_be_replayed() {
+        use casper_types::{account::AccountHash, crypto, SecretKey};
+
+        let secret_key = SecretKey::ed25519_from_bytes([5u8; 32]).unwrap();
+        let public_key = PublicKey::from(&secret_key);
+        let signer_pool = [SignerInfo {
+            account_hash: AccountHash::from(&public_key),
+            public_key: public_key.clone(),
+            weight: 1,
+            is_active: true,
+        }];
+        let digest = crypto::blake2b(b"payload");
+        let signature = encode_hex(&Vec::<u8>::from(crypto::sign(digest, &secret_key, &public_key)));
+
+        let signer = authorize_transaction(&digest, &signature, &signer_pool, false).unwrap();
+        assert_eq!(signer.public_key, public_key);
+        assert_eq!(
+            authorize_transaction(&digest, &signature, &signer_pool, true).unwrap_err(),
+            transaction_already_processed_error()
+        );
+        assert_eq!(
+            authorize_transaction(&crypto::blake2b(b"other"), &signature, &signer_pool, false).unwrap_err(),
+            invalid_signature_error()
+        );
+    }
+
+    /// A quote for `transaction_data`, its digest and the quote signer's
+    /// raw hex signature over that digest
+    fn signed_quote(transaction_data: &[u8]) -> (FeeQuote, [u8; 32], String, PublicKey) {
+        use casper_types::{bytesrepr::ToBytes, crypto, SecretKey};
+
+        let secret_key = SecretKey::ed25519_from_bytes([3u8; 32]).unwrap();
+        let quote_signer = PublicKey::from(&secret_key);
+        let quote = FeeQuote {
+            transaction_hash: crypto::blake2b(transaction_data),
+            fee_amount: 2_500,
+            fee_token: Some(casper_types::ContractHash::new([9u8; 32])),
+            expires_at: 1_000,
+        };
+        let quote_digest = crypto::blake2b(quote.to_bytes().unwrap());
+        let signature = crypto::sign(quote_digest, &secret_key, &quote_signer);
+        (quote, quote_digest, encode_hex(&Vec::<u8>::from(signature)), quote_signer)
+    }
+
+    #[test]
+    fn valid_quote_is_accepted_until_it_expires() {
+        let (quote, digest, signature, signer) = signed_quote(b"payload");
+        let transaction_digest = casper_types::crypto::blake2b(b"payload");
+
+        assert_eq!(check_fee_quote(&quote, &digest, &signature, &signer, &transaction_digest, 999, false), Ok(()));
+        assert_eq!(check_fee_quote(&quote, &digest, &signature, &signer, &transaction_digest, 1_000, false), Ok(()));
+        assert_eq!(
+            check_fee_quote(&quote, &digest, &signature, &signer, &transaction_digest, 1_001, false),
+            Err(quote_expired_error())
+        );
+    }
+
+    #[test]
+    fn used_quote_is_rejected() {
+        let (quote, digest, signature, signer) = signed_quote(b"payload");
+        let transaction_digest = casper_types::crypto::blake2b(b"payload");
+
+        assert_eq!(
+            check_fee_quote(&quote, &digest, &signature, &signer, &transaction_digest, 0, true),
+            Err(quote_already_used_error())
+        );
+    }
+
+    #[test]
+    fn quote_for_other_transaction_data_is_rejected() {
+        let (quote, digest, signature, signer) = signed_quote(b"payload");
+        let other_digest = casper_types::crypto::blake2b(b"other payload");
+
+        assert_eq!(
+            check_fee_quote(&quote, &digest, &signature, &signer, &other_digest, 0, false),
+            Err(invalid_quote_error())
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "unchecked-signatures"))]
+    fn tampered_quote_is_rejected() {
+        use casper_types::bytesrepr::ToBytes;
+
+        let (quote, _, signature, signer) = signed_quote(b"payload");
+        let transaction_digest = casper_types::crypto::blake2b(b"payload");
+        let tampered = FeeQuote { fee_amount: 1, ..quote };
+        let tampered_digest = casper_types::crypto::blake2b(tampered.to_bytes().unwrap());
+
+        assert_eq!(
+            check_fee_quote(&tampered, &tampered_digest, &signature, &signer, &transaction_digest, 0, false),
+            Err(invalid_quote_error())
+        );
+    }
+
+    /// Mirrors how both process entry points authorize a transaction,
+    /// record it as processed and then count it
+    #[test]
+    fn each_transaction_is_counted_once() {
+        use alloc::collections::BTreeSet;
+        use casper_types::{account::AccountHash, crypto, SecretKey};
+
+        let secret_key = SecretKey::ed25519_from_bytes([6u8; 32]).unwrap();
+        let public_key = PublicKey::from(&secret_key);
+        let signer_pool = [SignerInfo {
+            account_hash: AccountHash::from(&public_key),
+            public_key: public_key.clone(),
+            weight: 1,
+            is_active: true,
+        }];
+        let mut processed = BTreeSet::new();
+        let mut transaction_count = 0u64;
+
+        for payload in [&b"first"[..], b"first", b"second", b"second", b"first"] {
+            let digest = crypto::blake2b(payload);
+            let signature = encode_hex(&Vec::<u8>::from(crypto::sign(digest, &secret_key, &public_key)));
+            if authorize_transaction(&digest, &signature, &signer_pool, processed.contains(&digest)).is_ok() {
+                processed.insert(digest);
+                transaction_count += 1;
+            }
+        }
+        assert_eq!(transaction_count, 2);
+    }
+
+    #[test]
+    #[cfg(feature = "unchecked-signatures")]
+    fn unchecked_build_accepts_any_signature() {
+        const { assert!(SIGNATURES_UNCHECKED) };
+        let fixture: serde_json::Value = serde_json::from_str(PERMIT_VECTORS).unwrap();
+        let vector = &fixture["vectors"][0];
+        let public_key = PublicKey::from_hex(vector["public_key"].as_str().unwrap()).unwrap();
+        assert!(verify_signature(b"anything", "not a signature", &public_key));
+    }
+
+    #[test]
+    #[cfg(not(feature = "unchecked-signatures"))]
+    fn checked_build_rejects_garbage_signature() {
+        const { assert!(!SIGNATURES_UNCHECKED) };
+        let fixture: serde_json::Value = serde_json::from_str(PERMIT_VECTORS).unwrap();
+        let vector = &fixture["vectors"][0];
+        let public_key = PublicKey::from_hex(vector["public_key"].as_str().unwrap()).unwrap();
+        assert!(!verify_signature(b"anything", "not a signature", &public_key));
+    }
+}
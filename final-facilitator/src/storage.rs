@@ -1,14 +1,42 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use casper_contract::{
     contract_api::{runtime, storage as casper_storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{ContractHash, account::AccountHash};
+use casper_types::{ContractHash, PublicKey, account::AccountHash};
 
 use crate::constants::*;
-use crate::types::SignerInfo;
+use crate::signature::encode_hex;
+use crate::types::{AdminActionRecord, SignerInfo, StorageStats, TokenFeeBounds, TokenTransferLimits};
 
-/// Get supported tokens list
+/// Set the admin account
+pub fn set_admin(admin: AccountHash) {
+    let uref = runtime::get_key(ADMIN_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, admin);
+}
+
+/// Get the proposed admin awaiting acceptance, if any
+pub fn get_pending_admin() -> Option<AccountHash> {
+    let uref = runtime::get_key(PENDING_ADMIN_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set (or clear) the proposed admin
+pub fn set_pending_admin(pending_admin: Option<AccountHash>) {
+    let uref = runtime::get_key(PENDING_ADMIN_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, pending_admin);
+}
+
+/// Get supported tokens list, sorted ascending by contract hash
 pub fn get_supported_tokens() -> Vec<ContractHash> {
     let uref = runtime::get_key(SUPPORTED_TOKENS_KEY)
         .unwrap_or_revert()
@@ -18,7 +46,11 @@ pub fn get_supported_tokens() -> Vec<ContractHash> {
 }
 
 /// Set supported tokens list
-pub fn set_supported_tokens(tokens: Vec<ContractHash>) {
+///
+/// The list is sorted before being written so the stored registry always has
+/// a deterministic order, regardless of how the caller built it.
+pub fn set_supported_tokens(mut tokens: Vec<ContractHash>) {
+    tokens.sort_unstable();
     let uref = runtime::get_key(SUPPORTED_TOKENS_KEY)
         .unwrap_or_revert()
         .into_uref()
@@ -26,6 +58,25 @@ pub fn set_supported_tokens(tokens: Vec<ContractHash>) {
     casper_storage::write(uref, tokens);
 }
 
+/// Get the fee-eligible tokens, sorted ascending by contract hash
+pub fn get_fee_tokens() -> Vec<ContractHash> {
+    let uref = runtime::get_key(FEE_TOKENS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the fee-eligible tokens (sorted before writing)
+pub fn set_fee_tokens(mut tokens: Vec<ContractHash>) {
+    tokens.sort_unstable();
+    let uref = runtime::get_key(FEE_TOKENS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, tokens);
+}
+
 /// Get signer pool
 pub fn get_signer_pool() -> Vec<SignerInfo> {
     let uref = runtime::get_key(SIGNER_POOL_KEY)
@@ -71,6 +122,177 @@ pub fn get_max_fee_rate() -> u64 {
     casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
 }
 
+/// Set max fee rate
+pub fn set_max_fee_rate(rate: u64) {
+    let uref = runtime::get_key(MAX_FEE_RATE_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, rate);
+}
+
+/// Get the maximum accepted `transaction_data` size in bytes
+pub fn get_max_tx_size() -> u64 {
+    let uref = runtime::get_key(MAX_TX_SIZE_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the maximum accepted `transaction_data` size in bytes
+pub fn set_max_tx_size(max_tx_size: u64) {
+    let uref = runtime::get_key(MAX_TX_SIZE_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, max_tx_size);
+}
+
+/// Get the fee cap in basis points of the payment amount (0 = no cap)
+pub fn get_max_fee_bps() -> u32 {
+    let uref = runtime::get_key(MAX_FEE_BPS_OF_AMOUNT_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the fee cap in basis points of the payment amount
+pub fn set_max_fee_bps(max_fee_bps: u32) {
+    let uref = runtime::get_key(MAX_FEE_BPS_OF_AMOUNT_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, max_fee_bps);
+}
+
+/// Get the congestion level applied when a fee estimate names none
+pub fn get_default_congestion() -> u8 {
+    let uref = runtime::get_key(DEFAULT_CONGESTION_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the default congestion level
+pub fn set_default_congestion(congestion_level: u8) {
+    let uref = runtime::get_key(DEFAULT_CONGESTION_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, congestion_level);
+}
+
+/// Get the account allowed to feed the default congestion level, if any
+pub fn get_congestion_feeder() -> Option<AccountHash> {
+    let uref = runtime::get_key(CONGESTION_FEEDER_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set (or clear) the congestion feeder account
+pub fn set_congestion_feeder(feeder: Option<AccountHash>) {
+    let uref = runtime::get_key(CONGESTION_FEEDER_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, feeder);
+}
+
+/// Whether fees above the basis-point cap are rejected instead of clamped
+pub fn get_reject_fee_above_bps() -> bool {
+    let uref = runtime::get_key(REJECT_FEE_ABOVE_BPS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set whether fees above the basis-point cap are rejected
+pub fn set_reject_fee_above_bps(reject: bool) {
+    let uref = runtime::get_key(REJECT_FEE_ABOVE_BPS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, reject);
+}
+
+/// Get the number of active signers required to process payments
+pub fn get_min_active_signers() -> u32 {
+    let uref = runtime::get_key(MIN_ACTIVE_SIGNERS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the number of active signers required to process payments
+pub fn set_min_active_signers(min_active_signers: u32) {
+    let uref = runtime::get_key(MIN_ACTIVE_SIGNERS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, min_active_signers);
+}
+
+/// Get the upper bound on a single signer's weight
+pub fn get_max_signer_weight() -> u32 {
+    let uref = runtime::get_key(MAX_SIGNER_WEIGHT_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the upper bound on a single signer's weight
+pub fn set_max_signer_weight(max_signer_weight: u32) {
+    let uref = runtime::get_key(MAX_SIGNER_WEIGHT_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, max_signer_weight);
+}
+
+/// Get the number of signer selections made so far
+pub fn get_signer_selection_counter() -> u64 {
+    let uref = runtime::get_key(SIGNER_SELECTION_COUNTER_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the number of signer selections made so far
+pub fn set_signer_selection_counter(counter: u64) {
+    let uref = runtime::get_key(SIGNER_SELECTION_COUNTER_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, counter);
+}
+
+/// Get the number of transactions processed so far
+pub fn get_transaction_count() -> u64 {
+    let uref = runtime::get_key(TX_COUNTER_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the number of transactions processed so far
+pub fn set_transaction_count(count: u64) {
+    let uref = runtime::get_key(TX_COUNTER_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, count);
+}
+
 /// Check if contract is paused
 pub fn is_paused() -> bool {
     let uref = runtime::get_key(IS_PAUSED_KEY)
@@ -89,6 +311,24 @@ pub fn set_paused(paused: bool) {
     casper_storage::write(uref, paused);
 }
 
+/// Get the reason given for the current pause, if any
+pub fn get_pause_reason() -> Option<String> {
+    let uref = runtime::get_key(PAUSE_REASON_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set (or clear) the pause reason
+pub fn set_pause_reason(reason: Option<String>) {
+    let uref = runtime::get_key(PAUSE_REASON_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, reason);
+}
+
 /// Get fee recipient
 pub fn get_fee_recipient() -> AccountHash {
     let uref = runtime::get_key(FEE_RECIPIENT_KEY)
@@ -96,4 +336,213 @@ pub fn get_fee_recipient() -> AccountHash {
         .into_uref()
         .unwrap_or_revert();
     casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
-}
\ No newline at end of file
+}
+
+/// Get the admin action audit log (oldest first)
+pub fn get_admin_actions() -> Vec<AdminActionRecord> {
+    let uref = runtime::get_key(ADMIN_ACTIONS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the admin action audit log
+pub fn set_admin_actions(actions: Vec<AdminActionRecord>) {
+    let uref = runtime::get_key(ADMIN_ACTIONS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, actions);
+}
+
+/// Get the registry entry counts
+pub fn get_storage_stats() -> StorageStats {
+    let uref = runtime::get_key(STORAGE_STATS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the registry entry counts
+pub fn set_storage_stats(stats: StorageStats) {
+    let uref = runtime::get_key(STORAGE_STATS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, stats);
+}
+
+/// Get the public key fee quotes must be signed with, if configured
+pub fn get_quote_signer() -> Option<PublicKey> {
+    let uref = runtime::get_key(QUOTE_SIGNER_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the fee quote signer public key
+pub fn set_quote_signer(public_key: Option<PublicKey>) {
+    let uref = runtime::get_key(QUOTE_SIGNER_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, public_key);
+}
+
+/// Whether the fee quote with this digest has already been charged
+pub fn is_quote_used(quote_digest: &[u8; 32]) -> bool {
+    let uref = runtime::get_key(USED_QUOTES_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::dictionary_get::<bool>(uref, &encode_hex(quote_digest))
+        .unwrap_or_revert()
+        .unwrap_or(false)
+}
+
+/// Processed-transactions dictionary key for a transaction: the hex of its
+/// digest, so deduplication keeps no signature bytes
+pub fn processed_transaction_key(transaction_digest: &[u8; 32]) -> String {
+    encode_hex(transaction_digest)
+}
+
+/// Whether the transaction with this digest has already been processed
+pub fn is_transaction_processed(transaction_digest: &[u8; 32]) -> bool {
+    let uref = runtime::get_key(PROCESSED_TRANSACTIONS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::dictionary_get::<bool>(uref, &processed_transaction_key(transaction_digest))
+        .unwrap_or_revert()
+        .unwrap_or(false)
+}
+
+/// Record that the transaction with this digest has been processed
+pub fn mark_transaction_processed(transaction_digest: &[u8; 32]) {
+    let uref = runtime::get_key(PROCESSED_TRANSACTIONS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::dictionary_put(uref, &processed_transaction_key(transaction_digest), true);
+}
+
+/// Record that the fee quote with this digest has been charged
+pub fn mark_quote_used(quote_digest: &[u8; 32]) {
+    let uref = runtime::get_key(USED_QUOTES_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::dictionary_put(uref, &encode_hex(quote_digest), true);
+}
+
+/// Get the fee token used when a transaction names none, if configured
+pub fn get_default_fee_token() -> Option<ContractHash> {
+    let uref = runtime::get_key(DEFAULT_FEE_TOKEN_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the default fee token (`None` falls back to native CSPR)
+pub fn set_default_fee_token(token_contract: Option<ContractHash>) {
+    let uref = runtime::get_key(DEFAULT_FEE_TOKEN_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, token_contract);
+}
+
+/// Get the price table: resource id -> (amount, payment token; `None` is
+/// native CSPR in motes)
+pub fn get_resource_prices() -> BTreeMap<String, (u64, Option<ContractHash>)> {
+    let uref = runtime::get_key(RESOURCE_PRICES_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the price table
+pub fn set_resource_prices(resource_prices: BTreeMap<String, (u64, Option<ContractHash>)>) {
+    let uref = runtime::get_key(RESOURCE_PRICES_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, resource_prices);
+}
+
+/// Get the per-token fee bounds
+pub fn get_token_fee_bounds() -> Vec<TokenFeeBounds> {
+    let uref = runtime::get_key(TOKEN_FEE_BOUNDS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the per-token fee bounds
+pub fn set_token_fee_bounds(bounds: Vec<TokenFeeBounds>) {
+    let uref = runtime::get_key(TOKEN_FEE_BOUNDS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, bounds);
+}
+
+/// Get the per-token transfer limits
+pub fn get_token_transfer_limits() -> Vec<TokenTransferLimits> {
+    let uref = runtime::get_key(TOKEN_TRANSFER_LIMITS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the per-token transfer limits
+pub fn set_token_transfer_limits(limits: Vec<TokenTransferLimits>) {
+    let uref = runtime::get_key(TOKEN_TRANSFER_LIMITS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, limits);
+}
+
+/// Get the lifetime fees collected per token
+pub fn get_fees_collected() -> BTreeMap<ContractHash, u64> {
+    let uref = runtime::get_key(FEES_COLLECTED_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the lifetime fees collected per token
+pub fn set_fees_collected(fees_collected: BTreeMap<ContractHash, u64>) {
+    let uref = runtime::get_key(FEES_COLLECTED_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, fees_collected);
+}
+
+/// Get the fees held by the contract per token, awaiting withdrawal
+pub fn get_collected_fees() -> BTreeMap<ContractHash, u64> {
+    let uref = runtime::get_key(COLLECTED_FEES_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Set the fees held by the contract per token, awaiting withdrawal
+pub fn set_collected_fees(collected_fees: BTreeMap<ContractHash, u64>) {
+    let uref = runtime::get_key(COLLECTED_FEES_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    casper_storage::write(uref, collected_fees);
+}
@@ -120,6 +120,15 @@ impl TokenUtil {
         Ok(mint.decimals())
     }
 
+    /// `10^decimals` as a `Decimal`, failing instead of overflowing `u64`
+    /// for 20 or more decimals
+    pub fn decimals_scale(decimals: u8) -> Result<Decimal, KoraError> {
+        10u64
+            .checked_pow(decimals as u32)
+            .and_then(Decimal::from_u64)
+            .ok_or_else(|| KoraError::ValidationError(format!("Unsupported token decimals: {decimals}")))
+    }
+
     pub async fn get_token_price_and_decimals(
         mint: &Pubkey,
         rpc_client: &RpcClient,
@@ -154,8 +163,7 @@ impl TokenUtil {
         // Convert amount to Decimal with proper scaling
         let amount_decimal = Decimal::from_u64(amount)
             .ok_or_else(|| KoraError::ValidationError("Invalid token amount".to_string()))?;
-        let decimals_scale = Decimal::from_u64(10u64.pow(decimals as u32))
-            .ok_or_else(|| KoraError::ValidationError("Invalid decimals".to_string()))?;
+        let decimals_scale = Self::decimals_scale(decimals)?;
         let lamports_per_sol = Decimal::from_u64(LAMPORTS_PER_SOL)
             .ok_or_else(|| KoraError::ValidationError("Invalid LAMPORTS_PER_SOL".to_string()))?;
 
@@ -194,8 +202,7 @@ impl TokenUtil {
             .ok_or_else(|| KoraError::ValidationError("Invalid lamports value".to_string()))?;
         let lamports_per_sol_decimal = Decimal::from_u64(LAMPORTS_PER_SOL)
             .ok_or_else(|| KoraError::ValidationError("Invalid LAMPORTS_PER_SOL".to_string()))?;
-        let scale = Decimal::from_u64(10u64.pow(decimals as u32))
-            .ok_or_else(|| KoraError::ValidationError("Invalid decimals".to_string()))?;
+        let scale = Self::decimals_scale(decimals)?;
 
         // Calculate: (lamports * 10^decimals) / (LAMPORTS_PER_SOL * price)
         // Multiply before divide to preserve precision
@@ -352,8 +359,7 @@ impl TokenUtil {
                 let amount_decimal = Decimal::from_u64(*amount).ok_or_else(|| {
                     KoraError::ValidationError("Invalid transfer amount".to_string())
                 })?;
-                let decimals_scale = Decimal::from_u64(10u64.pow(*decimals as u32))
-                    .ok_or_else(|| KoraError::ValidationError("Invalid decimals".to_string()))?;
+                let decimals_scale = Self::decimals_scale(*decimals)?;
                 let lamports_per_sol = Decimal::from_u64(LAMPORTS_PER_SOL).ok_or_else(|| {
                     KoraError::ValidationError("Invalid LAMPORTS_PER_SOL".to_string())
                 })?;
@@ -662,6 +668,14 @@ mod tests_token {
 
     use super::*;
 
+    #[test]
+    fn test_decimals_scale() {
+        assert_eq!(TokenUtil::decimals_scale(18).unwrap(), dec!(1_000_000_000_000_000_000));
+        assert_eq!(TokenUtil::decimals_scale(19).unwrap(), dec!(10_000_000_000_000_000_000));
+        assert!(matches!(TokenUtil::decimals_scale(20), Err(KoraError::ValidationError(_))));
+        assert!(matches!(TokenUtil::decimals_scale(u8::MAX), Err(KoraError::ValidationError(_))));
+    }
+
     #[test]
     fn test_token_type_get_token_program_from_owner_spl() {
         let spl_token_owner = spl_token_interface::id();
@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 use casper_types::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
@@ -58,8 +58,470 @@ impl CLTyped for SignerInfo {
     }
 }
 
-/// Fee calculation result
+/// Kinds of admin operations recorded in the audit log
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AdminAction {
+    TokenAdded = 0,
+    TokensAdded = 1,
+    TokenRemoved = 2,
+    SignerAdded = 3,
+    SignerRemoved = 4,
+    ContractPaused = 5,
+    ContractUnpaused = 6,
+    QuoteSignerUpdated = 7,
+    TokenFeeBoundsUpdated = 8,
+    AdminProposed = 9,
+    AdminChanged = 10,
+    AdminProposalCancelled = 11,
+    MaxTxSizeUpdated = 12,
+    FeeTokenAdded = 13,
+    FeeTokenRemoved = 14,
+    SignerWeightReassigned = 15,
+    MinActiveSignersUpdated = 16,
+    DefaultFeeTokenUpdated = 17,
+    ResourcePriceSet = 18,
+    ResourcePriceRemoved = 19,
+    MaxFeeBpsUpdated = 20,
+    DefaultCongestionUpdated = 21,
+    CongestionFeederUpdated = 22,
+    MaxFeeRateUpdated = 23,
+    SignerActivityUpdated = 24,
+    FeesWithdrawn = 25,
+    BaseFeeRateUpdated = 26,
+    TokenLimitsUpdated = 27,
+    MaxSignerWeightUpdated = 28,
+}
+
+impl AdminAction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AdminAction::TokenAdded => "TokenAdded",
+            AdminAction::TokensAdded => "TokensAdded",
+            AdminAction::TokenRemoved => "TokenRemoved",
+            AdminAction::SignerAdded => "SignerAdded",
+            AdminAction::SignerRemoved => "SignerRemoved",
+            AdminAction::ContractPaused => "ContractPaused",
+            AdminAction::ContractUnpaused => "ContractUnpaused",
+            AdminAction::QuoteSignerUpdated => "QuoteSignerUpdated",
+            AdminAction::TokenFeeBoundsUpdated => "TokenFeeBoundsUpdated",
+            AdminAction::AdminProposed => "AdminProposed",
+            AdminAction::AdminChanged => "AdminChanged",
+            AdminAction::AdminProposalCancelled => "AdminProposalCancelled",
+            AdminAction::MaxTxSizeUpdated => "MaxTxSizeUpdated",
+            AdminAction::FeeTokenAdded => "FeeTokenAdded",
+            AdminAction::FeeTokenRemoved => "FeeTokenRemoved",
+            AdminAction::SignerWeightReassigned => "SignerWeightReassigned",
+            AdminAction::MinActiveSignersUpdated => "MinActiveSignersUpdated",
+            AdminAction::DefaultFeeTokenUpdated => "DefaultFeeTokenUpdated",
+            AdminAction::ResourcePriceSet => "ResourcePriceSet",
+            AdminAction::ResourcePriceRemoved => "ResourcePriceRemoved",
+            AdminAction::MaxFeeBpsUpdated => "MaxFeeBpsUpdated",
+            AdminAction::DefaultCongestionUpdated => "DefaultCongestionUpdated",
+            AdminAction::CongestionFeederUpdated => "CongestionFeederUpdated",
+            AdminAction::MaxFeeRateUpdated => "MaxFeeRateUpdated",
+            AdminAction::SignerActivityUpdated => "SignerActivityUpdated",
+            AdminAction::FeesWithdrawn => "FeesWithdrawn",
+            AdminAction::BaseFeeRateUpdated => "BaseFeeRateUpdated",
+            AdminAction::TokenLimitsUpdated => "TokenLimitsUpdated",
+            AdminAction::MaxSignerWeightUpdated => "MaxSignerWeightUpdated",
+        }
+    }
+}
+
+impl TryFrom<u8> for AdminAction {
+    type Error = casper_types::bytesrepr::Error;
+
+    fn try_from(tag: u8) -> Result<Self, Self::Error> {
+        match tag {
+            0 => Ok(AdminAction::TokenAdded),
+            1 => Ok(AdminAction::TokensAdded),
+            2 => Ok(AdminAction::TokenRemoved),
+            3 => Ok(AdminAction::SignerAdded),
+            4 => Ok(AdminAction::SignerRemoved),
+            5 => Ok(AdminAction::ContractPaused),
+            6 => Ok(AdminAction::ContractUnpaused),
+            7 => Ok(AdminAction::QuoteSignerUpdated),
+            8 => Ok(AdminAction::TokenFeeBoundsUpdated),
+            9 => Ok(AdminAction::AdminProposed),
+            10 => Ok(AdminAction::AdminChanged),
+            11 => Ok(AdminAction::AdminProposalCancelled),
+            12 => Ok(AdminAction::MaxTxSizeUpdated),
+            13 => Ok(AdminAction::FeeTokenAdded),
+            14 => Ok(AdminAction::FeeTokenRemoved),
+            15 => Ok(AdminAction::SignerWeightReassigned),
+            16 => Ok(AdminAction::MinActiveSignersUpdated),
+            17 => Ok(AdminAction::DefaultFeeTokenUpdated),
+            18 => Ok(AdminAction::ResourcePriceSet),
+            19 => Ok(AdminAction::ResourcePriceRemoved),
+            20 => Ok(AdminAction::MaxFeeBpsUpdated),
+            21 => Ok(AdminAction::DefaultCongestionUpdated),
+            22 => Ok(AdminAction::CongestionFeederUpdated),
+            23 => Ok(AdminAction::MaxFeeRateUpdated),
+            24 => Ok(AdminAction::SignerActivityUpdated),
+            25 => Ok(AdminAction::FeesWithdrawn),
+            26 => Ok(AdminAction::BaseFeeRateUpdated),
+            27 => Ok(AdminAction::TokenLimitsUpdated),
+            28 => Ok(AdminAction::MaxSignerWeightUpdated),
+            _ => Err(casper_types::bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+impl ToBytes for AdminAction {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        (*self as u8).to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        (*self as u8).serialized_length()
+    }
+}
+
+impl FromBytes for AdminAction {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        Ok((AdminAction::try_from(tag)?, remainder))
+    }
+}
+
+impl CLTyped for AdminAction {
+    fn cl_type() -> CLType {
+        CLType::U8
+    }
+}
+
+/// Audit log entry for a performed admin operation
+///
+/// Encoded exactly as the tuple `(action tag, actor, blocktime)`, which is
+/// its CLType.
+#[derive(Clone, Debug)]
+pub struct AdminActionRecord {
+    pub action: AdminAction,
+    pub actor: AccountHash,
+    pub blocktime: u64,
+}
+
+impl ToBytes for AdminActionRecord {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.action.to_bytes()?);
+        result.append(&mut self.actor.to_bytes()?);
+        result.append(&mut self.blocktime.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.action.serialized_length()
+            + self.actor.serialized_length()
+            + self.blocktime.serialized_length()
+    }
+}
+
+impl FromBytes for AdminActionRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (action, remainder) = AdminAction::from_bytes(bytes)?;
+        let (actor, remainder) = AccountHash::from_bytes(remainder)?;
+        let (blocktime, remainder) = u64::from_bytes(remainder)?;
+        
+        Ok((
+            AdminActionRecord {
+                action,
+                actor,
+                blocktime,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for AdminActionRecord {
+    fn cl_type() -> CLType {
+        CLType::Tuple3([
+            Box::new(AdminAction::cl_type()),
+            Box::new(AccountHash::cl_type()),
+            Box::new(u64::cl_type()),
+        ])
+    }
+}
+
+/// Floor and ceiling for fees charged in a given token, in the token's base
+/// units
 #[derive(Clone, Debug)]
+pub struct TokenFeeBounds {
+    pub token_contract: casper_types::ContractHash,
+    pub min_fee: u64,
+    pub max_fee: u64,
+    /// Reject fees above `max_fee` instead of clamping them to it
+    pub reject_above_max: bool,
+}
+
+impl ToBytes for TokenFeeBounds {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.token_contract.to_bytes()?);
+        result.append(&mut self.min_fee.to_bytes()?);
+        result.append(&mut self.max_fee.to_bytes()?);
+        result.append(&mut self.reject_above_max.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.token_contract.serialized_length()
+            + self.min_fee.serialized_length()
+            + self.max_fee.serialized_length()
+            + self.reject_above_max.serialized_length()
+    }
+}
+
+impl FromBytes for TokenFeeBounds {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (token_contract, remainder) = casper_types::ContractHash::from_bytes(bytes)?;
+        let (min_fee, remainder) = u64::from_bytes(remainder)?;
+        let (max_fee, remainder) = u64::from_bytes(remainder)?;
+        let (reject_above_max, remainder) = bool::from_bytes(remainder)?;
+        
+        Ok((
+            TokenFeeBounds {
+                token_contract,
+                min_fee,
+                max_fee,
+                reject_above_max,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for TokenFeeBounds {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+/// Smallest and largest payment accepted in a given token, in the token's
+/// base units
+#[derive(Clone, Debug)]
+pub struct TokenTransferLimits {
+    pub token_contract: casper_types::ContractHash,
+    pub min_amount: u64,
+    pub max_amount: u64,
+}
+
+impl TokenTransferLimits {
+    /// Whether `amount` lies within the limits, both ends included
+    pub fn admits(&self, amount: u64) -> bool {
+        (self.min_amount..=self.max_amount).contains(&amount)
+    }
+}
+
+impl ToBytes for TokenTransferLimits {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.token_contract.to_bytes()?);
+        result.append(&mut self.min_amount.to_bytes()?);
+        result.append(&mut self.max_amount.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.token_contract.serialized_length()
+            + self.min_amount.serialized_length()
+            + self.max_amount.serialized_length()
+    }
+}
+
+impl FromBytes for TokenTransferLimits {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (token_contract, remainder) = casper_types::ContractHash::from_bytes(bytes)?;
+        let (min_amount, remainder) = u64::from_bytes(remainder)?;
+        let (max_amount, remainder) = u64::from_bytes(remainder)?;
+        
+        Ok((
+            TokenTransferLimits {
+                token_contract,
+                min_amount,
+                max_amount,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for TokenTransferLimits {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+/// Registries whose entries are counted in [`StorageStats`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StoredItem {
+    SupportedToken,
+    FeeToken,
+    Signer,
+    ResourcePrice,
+    FeeCounter,
+    AdminAction,
+}
+
+/// Entry counts of the contract's registries.
+///
+/// Kept up to date as entries are added and removed, so reading them does
+/// not load the registries themselves.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StorageStats {
+    pub supported_tokens: u32,
+    pub fee_tokens: u32,
+    pub signers: u32,
+    pub resource_prices: u32,
+    pub fee_counters: u32,
+    pub admin_actions: u32,
+}
+
+impl StorageStats {
+    pub fn added(&mut self, item: StoredItem, count: u32) {
+        let counter = self.counter_mut(item);
+        *counter = counter.saturating_add(count);
+    }
+
+    pub fn removed(&mut self, item: StoredItem, count: u32) {
+        let counter = self.counter_mut(item);
+        *counter = counter.saturating_sub(count);
+    }
+
+    fn counter_mut(&mut self, item: StoredItem) -> &mut u32 {
+        match item {
+            StoredItem::SupportedToken => &mut self.supported_tokens,
+            StoredItem::FeeToken => &mut self.fee_tokens,
+            StoredItem::Signer => &mut self.signers,
+            StoredItem::ResourcePrice => &mut self.resource_prices,
+            StoredItem::FeeCounter => &mut self.fee_counters,
+            StoredItem::AdminAction => &mut self.admin_actions,
+        }
+    }
+}
+
+impl ToBytes for StorageStats {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.supported_tokens.to_bytes()?);
+        result.append(&mut self.fee_tokens.to_bytes()?);
+        result.append(&mut self.signers.to_bytes()?);
+        result.append(&mut self.resource_prices.to_bytes()?);
+        result.append(&mut self.fee_counters.to_bytes()?);
+        result.append(&mut self.admin_actions.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.supported_tokens.serialized_length()
+            + self.fee_tokens.serialized_length()
+            + self.signers.serialized_length()
+            + self.resource_prices.serialized_length()
+            + self.fee_counters.serialized_length()
+            + self.admin_actions.serialized_length()
+    }
+}
+
+impl FromBytes for StorageStats {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (supported_tokens, remainder) = u32::from_bytes(bytes)?;
+        let (fee_tokens, remainder) = u32::from_bytes(remainder)?;
+        let (signers, remainder) = u32::from_bytes(remainder)?;
+        let (resource_prices, remainder) = u32::from_bytes(remainder)?;
+        let (fee_counters, remainder) = u32::from_bytes(remainder)?;
+        let (admin_actions, remainder) = u32::from_bytes(remainder)?;
+        
+        Ok((
+            StorageStats {
+                supported_tokens,
+                fee_tokens,
+                signers,
+                resource_prices,
+                fee_counters,
+                admin_actions,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for StorageStats {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+/// Snapshot of the contract's configuration, returned by `get_config`
+#[derive(Clone, Debug, PartialEq)]
+pub struct FacilitatorConfig {
+    pub admin: AccountHash,
+    pub fee_recipient: AccountHash,
+    pub base_fee_rate: u64,
+    pub max_fee_rate: u64,
+    pub is_paused: bool,
+    pub supported_token_count: u32,
+    pub active_signer_count: u32,
+}
+
+impl ToBytes for FacilitatorConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.admin.to_bytes()?);
+        result.append(&mut self.fee_recipient.to_bytes()?);
+        result.append(&mut self.base_fee_rate.to_bytes()?);
+        result.append(&mut self.max_fee_rate.to_bytes()?);
+        result.append(&mut self.is_paused.to_bytes()?);
+        result.append(&mut self.supported_token_count.to_bytes()?);
+        result.append(&mut self.active_signer_count.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.admin.serialized_length()
+            + self.fee_recipient.serialized_length()
+            + self.base_fee_rate.serialized_length()
+            + self.max_fee_rate.serialized_length()
+            + self.is_paused.serialized_length()
+            + self.supported_token_count.serialized_length()
+            + self.active_signer_count.serialized_length()
+    }
+}
+
+impl FromBytes for FacilitatorConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (admin, remainder) = AccountHash::from_bytes(bytes)?;
+        let (fee_recipient, remainder) = AccountHash::from_bytes(remainder)?;
+        let (base_fee_rate, remainder) = u64::from_bytes(remainder)?;
+        let (max_fee_rate, remainder) = u64::from_bytes(remainder)?;
+        let (is_paused, remainder) = bool::from_bytes(remainder)?;
+        let (supported_token_count, remainder) = u32::from_bytes(remainder)?;
+        let (active_signer_count, remainder) = u32::from_bytes(remainder)?;
+        
+        Ok((
+            FacilitatorConfig {
+                admin,
+                fee_recipient,
+                base_fee_rate,
+                max_fee_rate,
+                is_paused,
+                supported_token_count,
+                active_signer_count,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for FacilitatorConfig {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+/// Fee calculation result
+#[derive(Clone, Debug, PartialEq)]
 pub struct FeeCalculation {
     pub total_fee: u64,
     pub base_fee: u64,
@@ -94,6 +556,90 @@ impl FeeCalculation {
     }
 }
 
+impl ToBytes for FeeCalculation {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.total_fee.to_bytes()?);
+        result.append(&mut self.base_fee.to_bytes()?);
+        result.append(&mut self.instruction_fee.to_bytes()?);
+        result.append(&mut self.lookup_table_fee.to_bytes()?);
+        result.append(&mut self.kora_signature_fee.to_bytes()?);
+        result.append(&mut self.payment_instruction_fee.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.total_fee.serialized_length()
+            + self.base_fee.serialized_length()
+            + self.instruction_fee.serialized_length()
+            + self.lookup_table_fee.serialized_length()
+            + self.kora_signature_fee.serialized_length()
+            + self.payment_instruction_fee.serialized_length()
+    }
+}
+
+impl FromBytes for FeeCalculation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (total_fee, remainder) = u64::from_bytes(bytes)?;
+        let (base_fee, remainder) = u64::from_bytes(remainder)?;
+        let (instruction_fee, remainder) = u64::from_bytes(remainder)?;
+        let (lookup_table_fee, remainder) = u64::from_bytes(remainder)?;
+        let (kora_signature_fee, remainder) = u64::from_bytes(remainder)?;
+        let (payment_instruction_fee, remainder) = u64::from_bytes(remainder)?;
+        
+        Ok((
+            FeeCalculation {
+                total_fee,
+                base_fee,
+                instruction_fee,
+                lookup_table_fee,
+                kora_signature_fee,
+                payment_instruction_fee,
+            },
+            remainder,
+        ))
+    }
+}
+
+/// Encoded as two triples of `u64`s, in field order: the total, base and
+/// instruction fees, then the lookup table, signature and payment
+/// instruction fees
+impl CLTyped for FeeCalculation {
+    fn cl_type() -> CLType {
+        let fee_triple = || Box::new(<(u64, u64, u64)>::cl_type());
+        CLType::Tuple2([fee_triple(), fee_triple()])
+    }
+}
+
+/// Server-issued fee quote that binds the fee charged for one transaction
+#[derive(Clone, Debug)]
+pub struct FeeQuote {
+    /// Blake2b-256 hash of the quoted `transaction_data`
+    pub transaction_hash: [u8; 32],
+    pub fee_amount: u64,
+    pub fee_token: Option<casper_types::ContractHash>,
+    /// Expiry in block time milliseconds
+    pub expires_at: u64,
+}
+
+impl ToBytes for FeeQuote {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.transaction_hash.to_bytes()?);
+        result.append(&mut self.fee_amount.to_bytes()?);
+        result.append(&mut self.fee_token.to_bytes()?);
+        result.append(&mut self.expires_at.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.transaction_hash.serialized_length()
+            + self.fee_amount.serialized_length()
+            + self.fee_token.serialized_length()
+            + self.expires_at.serialized_length()
+    }
+}
+
 /// Price calculator configuration
 #[derive(Clone, Debug)]
 pub struct PriceConfig {
@@ -132,4 +678,152 @@ pub struct TransactionMetadata {
     pub uses_lookup_tables: bool,
     pub requires_payment: bool,
     pub fee_token: Option<casper_types::ContractHash>,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fee_calculation_bytesrepr_round_trip() {
+        let fee_calc = FeeCalculation::new(100_000, 20_000, 50_000, 5_000, 2_000);
+        let bytes = fee_calc.to_bytes().unwrap();
+        assert_eq!(bytes.len(), fee_calc.serialized_length());
+        
+        let (decoded, remainder) = FeeCalculation::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, fee_calc);
+        assert!(remainder.is_empty());
+    }
+
+    /// What `estimate_fee_breakdown` returns, read back the way a caller
+    /// decodes it
+    #[test]
+    fn fee_breakdown_reads_back_from_its_cl_value() {
+        let fee_calc = crate::fee::calculate_total_fees(1_200, 3, true, true, 1_500);
+        let value = casper_types::CLValue::from_t(fee_calc.clone()).unwrap();
+        assert_eq!(*value.cl_type(), FeeCalculation::cl_type());
+
+        let decoded: FeeCalculation = value.clone().into_t().unwrap();
+        assert_eq!(decoded, fee_calc);
+
+        // The same bytes under the declared tuple type, for clients without
+        // this crate
+        let ((total_fee, base_fee, instruction_fee), (lookup_table_fee, kora_signature_fee, payment_instruction_fee)):
+            ((u64, u64, u64), (u64, u64, u64)) = value.into_t().unwrap();
+        assert_eq!(
+            FeeCalculation::new(base_fee, instruction_fee, lookup_table_fee, kora_signature_fee, payment_instruction_fee),
+            fee_calc
+        );
+        assert_eq!(total_fee, fee_calc.total_fee);
+    }
+
+    #[test]
+    fn config_reflects_admin_changes_after_round_trip() {
+        // Two tokens added, then one removed
+        let mut stats = StorageStats::default();
+        stats.added(StoredItem::SupportedToken, 2);
+        stats.removed(StoredItem::SupportedToken, 1);
+        let config = FacilitatorConfig {
+            admin: AccountHash::new([1u8; 32]),
+            fee_recipient: AccountHash::new([2u8; 32]),
+            base_fee_rate: 1_500,
+            max_fee_rate: 10_000,
+            is_paused: true,
+            supported_token_count: stats.supported_tokens,
+            active_signer_count: 2,
+        };
+
+        let bytes = config.to_bytes().unwrap();
+        assert_eq!(bytes.len(), config.serialized_length());
+        let (decoded, remainder) = FacilitatorConfig::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded.admin, AccountHash::new([1u8; 32]));
+        assert_eq!(decoded.base_fee_rate, 1_500);
+        assert!(decoded.is_paused);
+        assert_eq!(decoded.supported_token_count, 1);
+        assert_eq!(decoded.active_signer_count, 2);
+        assert_eq!(decoded, config);
+    }
+
+    #[test]
+    fn transfer_limits_admit_only_in_band_amounts() {
+        let limits = TokenTransferLimits {
+            token_contract: casper_types::ContractHash::new([1u8; 32]),
+            min_amount: 1_000,
+            max_amount: 5_000_000,
+        };
+        assert!(!limits.admits(999));
+        assert!(limits.admits(1_000));
+        assert!(limits.admits(250_000));
+        assert!(limits.admits(5_000_000));
+        assert!(!limits.admits(5_000_001));
+    }
+
+    #[test]
+    fn admin_action_records_round_trip_for_every_action() {
+        let actor = AccountHash::new([4u8; 32]);
+        let mut tag = 0u8;
+        while let Ok(action) = AdminAction::try_from(tag) {
+            let record = AdminActionRecord { action, actor, blocktime: 42 };
+            let bytes = record.to_bytes().unwrap();
+            assert_eq!(bytes.len(), record.serialized_length());
+
+            let (decoded, rest) = AdminActionRecord::from_bytes(&bytes).unwrap();
+            assert!(rest.is_empty());
+            assert_eq!(decoded.action, action);
+            assert_eq!(decoded.action as u8, tag);
+            assert_eq!(decoded.actor, actor);
+            assert_eq!(decoded.blocktime, 42);
+            tag += 1;
+        }
+        assert_eq!(tag, AdminAction::MaxSignerWeightUpdated as u8 + 1);
+        assert!(AdminActionRecord::from_bytes(&[tag]).is_err());
+    }
+
+    #[test]
+    fn admin_action_record_reads_back_as_its_cl_type() {
+        let actor = AccountHash::new([4u8; 32]);
+        let record = AdminActionRecord { action: AdminAction::ContractPaused, actor, blocktime: 42 };
+
+        let value = casper_types::CLValue::from_t(alloc::vec![record]).unwrap();
+        assert_eq!(
+            *value.cl_type(),
+            CLType::List(Box::new(CLType::Tuple3([
+                Box::new(CLType::U8),
+                Box::new(CLType::ByteArray(32)),
+                Box::new(CLType::U64),
+            ])))
+        );
+        let decoded: Vec<(u8, AccountHash, u64)> = value.into_t().unwrap();
+        assert_eq!(decoded, alloc::vec![(AdminAction::ContractPaused as u8, actor, 42)]);
+    }
+
+    #[test]
+    fn storage_stats_track_adds_and_removes() {
+        let mut stats = StorageStats::default();
+        stats.added(StoredItem::SupportedToken, 3);
+        stats.added(StoredItem::FeeToken, 1);
+        stats.added(StoredItem::Signer, 2);
+        stats.removed(StoredItem::SupportedToken, 1);
+        stats.removed(StoredItem::Signer, 1);
+        assert_eq!(
+            stats,
+            StorageStats {
+                supported_tokens: 2,
+                fee_tokens: 1,
+                signers: 1,
+                ..StorageStats::default()
+            }
+        );
+
+        // Counters never wrap below zero
+        stats.removed(StoredItem::ResourcePrice, 1);
+        assert_eq!(stats.resource_prices, 0);
+
+        let bytes = stats.to_bytes().unwrap();
+        assert_eq!(bytes.len(), stats.serialized_length());
+        let (decoded, remainder) = StorageStats::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, stats);
+        assert!(remainder.is_empty());
+    }
+}
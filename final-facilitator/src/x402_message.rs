@@ -0,0 +1,76 @@
+//! Canonical x402 message construction.
+//!
+//! The facilitator contract and the standalone server must build
+//! byte-identical messages, so both compile this one file: the contract as
+//! a regular module, the server through a `#[path]` module. Keep it
+//! `no_std` and free of other crate-local imports.
+
+use alloc::format;
+use alloc::string::String;
+
+/// Prefix Casper Wallet prepends to every message it signs
+pub const CASPER_MESSAGE_PREFIX: &str = "Casper Message:\n";
+
+/// Action tag of payment claims (permits)
+pub const CLAIM_ACTION_TAG: &str = "x402-casper";
+
+/// Build the message a payer signs for `action_tag`.
+///
+/// Layout (no trailing newline):
+///
+/// `Casper Message:\n<action_tag>:<chain_name>:<contract_hash>:<recipient>:<amount>:<nonce>:<deadline>`
+///
+/// `contract_hash` and `recipient` are lowercase hex without their
+/// `hash-`/`contract-`/`account-hash-` prefixes, `amount` is the decimal
+/// token amount in base units, and `deadline` is in milliseconds since the
+/// Unix epoch (the unit of `runtime::get_blocktime()`).
+pub fn build(
+    chain_name: &str,
+    contract_hash: &str,
+    recipient: &str,
+    amount: &str,
+    nonce: u64,
+    deadline: u64,
+    action_tag: &str,
+) -> String {
+    format!(
+        "{}{}:{}:{}:{}:{}:{}:{}",
+        CASPER_MESSAGE_PREFIX, action_tag, chain_name, contract_hash, recipient, amount, nonce, deadline
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    // Run by both crates, so each checks its build against the same vectors
+    const PERMIT_VECTORS: &str =
+        include_str!("../../facilitator-standalone/tests/fixtures/permit_vectors.json");
+
+    #[test]
+    fn claim_messages_match_permit_vectors() {
+        let fixture: serde_json::Value = serde_json::from_str(PERMIT_VECTORS).unwrap();
+        for vector in fixture["vectors"].as_array().unwrap() {
+            let message = build(
+                vector["chain_name"].as_str().unwrap(),
+                vector["contract_hash"].as_str().unwrap(),
+                vector["recipient"].as_str().unwrap(),
+                vector["amount"].as_str().unwrap(),
+                vector["nonce"].as_u64().unwrap(),
+                vector["deadline"].as_u64().unwrap(),
+                CLAIM_ACTION_TAG,
+            );
+            assert_eq!(message, vector["message"].as_str().unwrap(), "{}", vector["name"]);
+        }
+    }
+
+    #[test]
+    fn action_tag_separates_message_domains() {
+        let claim = build("casper-test", "6a54", "ab12", "1000", 0, 1, CLAIM_ACTION_TAG);
+        let other = build("casper-test", "6a54", "ab12", "1000", 0, 1, "x402-casper-refund");
+        assert_ne!(claim, other);
+        assert!(other.starts_with("Casper Message:\nx402-casper-refund:casper-test:"));
+    }
+}